@@ -0,0 +1,38 @@
+use std::error::Error;
+use std::vec::Vec;
+
+use crate::{Answer, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let input = input.trim();
+
+        let mut nums : Vec<u32> = Vec::new();
+        for c in input.chars() {
+            nums.push(c.to_digit(10).unwrap());
+        };
+
+        Ok(nums)
+    }
+
+    fn part1(_nums: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part2(nums: &Self::Input) -> Answer {
+        let mut sum = 0;
+
+        let len = nums.len();
+        for (i, n) in nums.iter().enumerate() {
+            if *n == nums[(i + (len / 2)) % len] {
+                sum += n;
+            };
+        };
+
+        sum.into()
+    }
+}
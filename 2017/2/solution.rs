@@ -1,8 +1,10 @@
-use std::fs;
+use std::error::Error;
 use std::vec::Vec;
 
+use crate::{Answer, Solution};
+
 fn min_max_cksum(row : &Vec<u32>) -> u32 {
-    let (mut min, mut max) : (u32, u32) = (std::u32::MAX, 0);
+    let (mut min, mut max) : (u32, u32) = (u32::MAX, 0);
     for i in row {
         if *i < min {
             min = *i;
@@ -39,22 +41,32 @@ fn cksum<F>(spreadsheet : &Vec<Vec<u32>>, row_cksum : F) -> u32
     cksum
 }
 
-fn main() -> Result<(), Box<std::error::Error>> {
-    let mut input = fs::read_to_string("input.tsv")?;
-    let _ = input.pop();
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Vec<u32>>;
 
-    let mut spreadsheet : Vec<Vec<u32>> = Vec::new();
+    fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let input = input.trim();
 
-    for line in input.split("\n") {
-        let mut row : Vec<u32> = Vec::new();
-        for cell in line.split("\t") {
-            row.push(cell.parse::<u32>()?);
+        let mut spreadsheet : Vec<Vec<u32>> = Vec::new();
+
+        for line in input.split("\n") {
+            let mut row : Vec<u32> = Vec::new();
+            for cell in line.split("\t") {
+                row.push(cell.parse::<u32>()?);
+            }
+            spreadsheet.push(row);
         }
-        spreadsheet.push(row);
+
+        Ok(spreadsheet)
     }
 
-    println!("Part 1: {}", cksum(&spreadsheet, min_max_cksum));
-    println!("Part 2: {}", cksum(&spreadsheet, div_cksum));
+    fn part1(spreadsheet: &Self::Input) -> Answer {
+        cksum(spreadsheet, min_max_cksum).into()
+    }
 
-    Ok(())
+    fn part2(spreadsheet: &Self::Input) -> Answer {
+        cksum(spreadsheet, div_cksum).into()
+    }
 }
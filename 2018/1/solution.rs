@@ -1,7 +1,9 @@
-use std::fs;
+use std::error::Error;
 use std::collections::HashSet;
 use std::vec::Vec;
 
+use crate::{Answer, Solution};
+
 fn part_1(deltas: &Vec<i32>) -> i32 {
     let mut frequency: i32 = 0;
 
@@ -30,20 +32,25 @@ fn part_2(deltas: &Vec<i32>) -> i32 {
 }
 
 
-fn main() {
-    let contents: String = match fs::read_to_string("input.txt") {
-        Ok(buffer) => buffer,
-        Err(_) => String::new()
-    };
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<i32>;
 
-    let mut deltas: Vec<i32> = Vec::new();
-    for line in contents.split("\n") {
-        let _ = match line.parse() {
-            Ok(i) => deltas.push(i),
-            Err(_) => ()
+    fn parse(contents: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let mut deltas: Vec<i32> = Vec::new();
+        for line in contents.split("\n") {
+            if let Ok(i) = line.parse() { deltas.push(i) };
         };
-    };
 
-    println!("Part 1: frequency is {}", part_1(&deltas));
-    println!("Part 2: first frequency reached twice is {}", part_2(&deltas));
+        Ok(deltas)
+    }
+
+    fn part1(deltas: &Self::Input) -> Answer {
+        part_1(deltas).into()
+    }
+
+    fn part2(deltas: &Self::Input) -> Answer {
+        part_2(deltas).into()
+    }
 }
@@ -1,7 +1,10 @@
-use std::{env, fmt, fs};
+use std::{error::Error, fmt};
 use std::cmp::PartialEq;
 
-struct Point {
+use crate::{Answer, Solution};
+
+#[derive(Clone)]
+pub struct Point {
     x: i64,
     y: i64,
     v_x: i64,
@@ -12,7 +15,7 @@ impl Point {
     fn new(line: String) -> Self {
         let tokens = line
             .split(|c: char| !c.is_ascii_digit() && c != '-')
-            .filter(|&s| s != "")
+            .filter(|&s| !s.is_empty())
             .map(|s| s.parse::<i64>().unwrap())
             .collect::<Vec<i64>>();
 
@@ -46,7 +49,7 @@ impl Point {
         ret
     }
 
-    fn has_neighbor(&self, points: &Vec<Point>) -> bool {
+    fn has_neighbor(&self, points: &[Point]) -> bool {
         self.neighbors().iter().any(|n| points.contains(n))
     }
 }
@@ -63,7 +66,7 @@ impl PartialEq for Point {
     }
 }
 
-fn print_message(points: &Vec<Point>) {
+fn print_message(points: &[Point]) {
     let mut x_min = points[0].x;
     let mut y_min = points[0].y;
     let mut x_max = points[0].x;
@@ -82,17 +85,33 @@ fn print_message(points: &Vec<Point>) {
             y_min = point.y;
         };
     };
+    let mut message = String::new();
     for y in y_min..(y_max + 1) {
-        print!("\n");
+        message.push('\n');
         for x in x_min..(x_max + 1) {
-            if points.contains(&Point {x: x, y: y, v_x: 0, v_y: 0}) {
-                print!("#");
+            if points.contains(&Point {x, y, v_x: 0, v_y: 0}) {
+                message.push('#');
             } else {
-                print!(".")
+                message.push('.');
             };
         };
     };
-    println!("\n");
+    message.push_str("\n\n");
+    crate::detail(&message);
+}
+
+fn read_message(points: &[Point]) -> Result<String, ocr::OcrError> {
+    let x_min = points.iter().map(|p| p.x).min().unwrap();
+    let x_max = points.iter().map(|p| p.x).max().unwrap();
+    let y_min = points.iter().map(|p| p.y).min().unwrap();
+    let y_max = points.iter().map(|p| p.y).max().unwrap();
+
+    let mut grid = vec![vec![false; (x_max - x_min + 1) as usize]; (y_max - y_min + 1) as usize];
+    for point in points.iter() {
+        grid[(point.y - y_min) as usize][(point.x - x_min) as usize] = true;
+    };
+
+    ocr::recognize(&grid)
 }
 
 fn simulate(mut points: Vec<Point>) -> (Vec<Point>, u64) {
@@ -109,20 +128,40 @@ fn simulate(mut points: Vec<Point>) -> (Vec<Point>, u64) {
     };
 }
 
-fn main() -> Result<(), std::io::Error> {
-    let args: Vec<String> = env::args().collect();
-    let contents: String = fs::read_to_string(&args[1])?;
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Point>;
 
-    let mut points: Vec<Point> = Vec::new();
-    for line in contents.split("\n") {
-        if line != "" {
-            points.push(Point::new(line.to_string()));
+    fn parse(contents: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let mut points: Vec<Point> = Vec::new();
+        for line in contents.split("\n") {
+            if !line.is_empty() {
+                points.push(Point::new(line.to_string()));
+            };
         };
-    };
 
-    let (message, num_seconds) = simulate(points);
-    println!("Number of seconds to resolve = {}", num_seconds);
-    print_message(&message);
+        Ok(points)
+    }
 
-    Ok(())
+    // the message the points spell out, once they've all come together. if
+    // it can't be read, the points get drawn so someone can have a look, and
+    // the part's left unsolved
+    fn part1(points: &Self::Input) -> Answer {
+        let (message, _) = simulate(points.clone());
+        match read_message(&message) {
+            Ok(text) => text.into(),
+            Err(e) => {
+                crate::detail(&format!("Couldn't read message: {}\n", e));
+                print_message(&message);
+                Answer::Unsolved
+            }
+        }
+    }
+
+    // number of seconds to resolve
+    fn part2(points: &Self::Input) -> Answer {
+        let (_, num_seconds) = simulate(points.clone());
+        num_seconds.into()
+    }
 }
@@ -1,4 +1,6 @@
-use std::env;
+use std::error::Error;
+
+use crate::{Answer, Solution};
 
 type Grid<T> = Vec<Vec<T>>;
 
@@ -81,17 +83,24 @@ fn find_maximum_power(summed_area_table: &Grid<i64>) -> (i64, usize, usize, usiz
     (max.unwrap(), max_x.unwrap(), max_y.unwrap(), max_d.unwrap())
 }
 
-fn main() -> Result<(), std::io::Error> {
-    let serial: i64 = env::args()
-        .nth(1)
-        .unwrap()
-        .parse::<i64>()
-        .unwrap();
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = i64;
+
+    fn parse(contents: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(contents.trim().parse::<i64>()?)
+    }
 
-    let grid = construct_grid(serial);
-    let summed_area_table = construct_summed_area_table(&grid);
-    let (max, max_x, max_y, max_d) = find_maximum_power(&summed_area_table);
-    println!("Power {} found at {},{},{}", max, max_x, max_y, max_d);
+    fn part1(_serial: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
 
-    Ok(())
+    // where the most powerful square of any size is, and its size
+    fn part2(&serial: &Self::Input) -> Answer {
+        let grid = construct_grid(serial);
+        let summed_area_table = construct_summed_area_table(&grid);
+        let (_, max_x, max_y, max_d) = find_maximum_power(&summed_area_table);
+        format!("{},{},{}", max_x, max_y, max_d).into()
+    }
 }
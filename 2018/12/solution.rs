@@ -1,7 +1,8 @@
-use std::env;
-use std::fs;
+use std::error::Error;
 use std::collections::HashSet;
 
+use crate::{Answer, Solution};
+
 type State = Vec<(char, i64)>;
 
 fn state_to_string(slice: &[(char, i64)]) -> String {
@@ -11,10 +12,14 @@ fn state_to_string(slice: &[(char, i64)]) -> String {
         .collect()
 }
 
+// for debugging
+#[allow(dead_code)]
 fn print_state(state: &State) {
     println!("{}", state_to_string(&state[..]))
 }
 
+// no longer called, but kept from an earlier go at trimming the state
+#[allow(dead_code)]
 fn cut_dots(mut state: State) -> State {
     let mut curr_plant = state[0].0;
     while curr_plant != '#' && state.len() > 1 {
@@ -105,47 +110,54 @@ fn part_2(state: State, rules: &HashSet<String>) -> i64 {
     sum_at_cycle_start + (difference * remaining_gen)
 }
 
-fn main() -> Result<(), std::io::Error> {
-    let args: Vec<String> = env::args().collect();
-    let contents: String = fs::read_to_string(&args[1])?;
+pub struct Day12;
 
-    let mut lines: Vec<String> = Vec::new();
-    for line in contents.split("\n") {
-        if line != "" {
-            lines.push(line.to_string());
-        };
-    };
+impl Solution for Day12 {
+    type Input = (State, HashSet<String>);
 
-    let initial_state: State = lines[0]
-        .split(": ")
-        .skip(1)
-        .take(1)
-        .collect::<String>()
-        .chars()
-        .zip(0..)
-        .collect();
+    fn parse(contents: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let mut lines: Vec<String> = Vec::new();
+        for line in contents.split("\n") {
+            if !line.is_empty() {
+                lines.push(line.to_string());
+            };
+        };
 
-    let rules = lines
-        .iter()
-        .skip(1)
-        .map(|l| l
-            .split(|c| c == '=' || c == '>' || c == ' ')
-            .filter(|s| s != &"")
-            .collect::<Vec<&str>>())
-        .filter_map(|ts|
-            if ts[1] == "#" {
-                Some(ts[0].to_string())
-            } else {
-                None
-            }
-        )
-        .collect::<HashSet<String>>();
-
-    println!("Part 1: the sum of the pot numbers is {}",
-             part_1(&initial_state, &rules));
-
-    println!("Part 2: the sum of the pot numbers is {}",
-             part_2(initial_state.clone(), &rules));
-
-    Ok(())
+        let initial_state: State = lines[0]
+            .split(": ")
+            .skip(1)
+            .take(1)
+            .collect::<String>()
+            .chars()
+            .zip(0..)
+            .collect();
+
+        let rules = lines
+            .iter()
+            .skip(1)
+            .map(|l| l
+                .split(['=', '>', ' '])
+                .filter(|s| s != &"")
+                .collect::<Vec<&str>>())
+            .filter_map(|ts|
+                if ts[1] == "#" {
+                    Some(ts[0].to_string())
+                } else {
+                    None
+                }
+            )
+            .collect::<HashSet<String>>();
+
+        Ok((initial_state, rules))
+    }
+
+    // the sum of the pot numbers after 20 generations
+    fn part1((initial_state, rules): &Self::Input) -> Answer {
+        part_1(initial_state, rules).into()
+    }
+
+    // the sum of the pot numbers after fifty billion generations
+    fn part2((initial_state, rules): &Self::Input) -> Answer {
+        part_2(initial_state.clone(), rules).into()
+    }
 }
@@ -1,8 +1,9 @@
-use std::env;
-use std::fs;
+use std::error::Error;
 use std::fmt;
 use std::collections::BTreeSet;
 
+use crate::{Answer, Solution};
+
 #[derive(PartialEq, Eq, Clone, Copy)]
 enum CartDirection {
     Up,
@@ -97,7 +98,7 @@ impl fmt::Display for Track {
 }
 
 #[derive(Clone)]
-struct GridPoint {
+pub struct GridPoint {
     cart: Option<Cart>,
     track: Option<Track>
 }
@@ -143,6 +144,8 @@ type Grid = Vec<Vec<GridPoint>>;
 type Position = (usize, usize);
 type Carts = BTreeSet<Position>;
 
+// for debugging
+#[allow(dead_code)]
 fn print_grid(grid: &Grid) {
     grid
         .iter()
@@ -221,32 +224,42 @@ fn part_2(mut grid: Grid, mut carts: Carts) -> Position {
 }
 
 
-fn main() -> Result<(), std::io::Error> {
-    let args: Vec<String> = env::args().collect();
-    let contents: String = fs::read_to_string(&args[1])?;
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = (Grid, Carts);
 
-    let mut carts: Carts = BTreeSet::new();
-    let mut grid: Grid = Vec::new();
+    fn parse(contents: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let mut carts: Carts = BTreeSet::new();
+        let mut grid: Grid = Vec::new();
 
-    for (r, line) in contents.split("\n").enumerate() {
-        if line != "" {
-            let mut row = Vec::new();
-            for (c, symb) in line.chars().enumerate() {
-                let gp = GridPoint::from_char(symb);
-                if gp.cart.is_some() {
-                    carts.insert((r, c));
+        for (r, line) in contents.split("\n").enumerate() {
+            if !line.is_empty() {
+                let mut row = Vec::new();
+                for (c, symb) in line.chars().enumerate() {
+                    let gp = GridPoint::from_char(symb);
+                    if gp.cart.is_some() {
+                        carts.insert((r, c));
+                    };
+                    row.push(gp);
                 };
-                row.push(gp);
-            };
 
-            grid.push(row);
+                grid.push(row);
+            };
         };
-    };
 
-    let (r, c) = part_1(grid.to_vec(), carts.clone());
-    println!("Part 1: first collision occurs at {},{}", c, r);
-    let (r, c) = part_2(grid.to_vec(), carts.clone());
-    println!("Part 2: last cart standing is at {},{}", c, r);
+        Ok((grid, carts))
+    }
+
+    // where the first collision occurs
+    fn part1((grid, carts): &Self::Input) -> Answer {
+        let (r, c) = part_1(grid.to_vec(), carts.clone());
+        format!("{},{}", c, r).into()
+    }
 
-    Ok(())
+    // where the last cart standing is
+    fn part2((grid, carts): &Self::Input) -> Answer {
+        let (r, c) = part_2(grid.to_vec(), carts.clone());
+        format!("{},{}", c, r).into()
+    }
 }
@@ -1,7 +1,9 @@
-use std::fs;
+use std::error::Error;
 use std::vec::Vec;
 use std::collections::HashMap;
 
+use crate::{Answer, Solution};
+
 fn part_1(tags: &Vec<String>) -> u16 {
     let (mut exactly_two, mut exactly_three) = (0, 0);
 
@@ -28,7 +30,7 @@ fn part_1(tags: &Vec<String>) -> u16 {
     exactly_two * exactly_three
 }
 
-fn strcmp(s1: &String, s2: &String) -> u64 {
+fn strcmp(s1: &str, s2: &str) -> u64 {
     let mut diff = 0;
     for (c1, c2) in s1.chars().zip(s2.chars()) {
         if c1 != c2 {
@@ -39,16 +41,14 @@ fn strcmp(s1: &String, s2: &String) -> u64 {
     diff
 }
 
-fn common_letters(s1: &String, s2: &String) -> String {
-    let mut i = 0;
-    for (c1, c2) in s1.chars().zip(s2.chars()) {
+fn common_letters(s1: &str, s2: &str) -> String {
+    for (i, (c1, c2)) in s1.chars().zip(s2.chars()).enumerate() {
         if c1 != c2 {
             return [&s1[..i], &s2[i + 1..]].join("");
         };
-        i += 1
     };
 
-    s1.clone()
+    s1.to_string()
 }
 
 fn part_2(tags: &Vec<String>) -> String {
@@ -63,18 +63,27 @@ fn part_2(tags: &Vec<String>) -> String {
     String::from("")
 }
 
-fn main() -> Result<(), std::io::Error> {
-    let contents: String = fs::read_to_string("input.txt")?;
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<String>;
 
-    let mut tags: Vec<String> = Vec::new();
-    for tag in contents.split("\n") {
-        if tag != "" {
-            tags.push(tag.to_string());
+    fn parse(contents: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let mut tags: Vec<String> = Vec::new();
+        for tag in contents.split("\n") {
+            if !tag.is_empty() {
+                tags.push(tag.to_string());
+            };
         };
-    };
 
-    println!("Part 1: checksum = {}", part_1(&tags));
-    println!("Part 2: common tag letters = {}", part_2(&tags));
+        Ok(tags)
+    }
+
+    fn part1(tags: &Self::Input) -> Answer {
+        part_1(tags).into()
+    }
 
-    Ok(())
+    fn part2(tags: &Self::Input) -> Answer {
+        part_2(tags).into()
+    }
 }
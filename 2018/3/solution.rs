@@ -1,8 +1,10 @@
-use std::fs;
+use std::error::Error;
 use std::fmt;
 use std::vec::Vec;
 use std::collections::HashMap;
 
+use crate::{Answer, Solution};
+
 #[derive(PartialEq, Eq, Hash)]
 struct Point {
     x: i64,
@@ -18,7 +20,7 @@ impl fmt::Display for Point {
 type ClaimId = i64;
 type Canvas = HashMap<Point, Vec<ClaimId>>;
 
-struct Claim {
+pub struct Claim {
     id: i64,
     origin: Point,
     width: i64,
@@ -33,10 +35,10 @@ impl fmt::Display for Claim {
 }
 
 impl Claim {
-    fn new(line: &String) -> Claim {
+    fn new(line: &str) -> Claim {
         let fields: Vec<i64> = line
-            .split(|c| vec![' ', '#', '@', ',', ':', 'x'].contains(&c))
-            .filter(|f| *f != "")
+            .split(|c| [' ', '#', '@', ',', ':', 'x'].contains(&c))
+            .filter(|f| !f.is_empty())
             .map(|f| f.parse::<i64>().unwrap())
             .collect();
 
@@ -48,11 +50,11 @@ impl Claim {
         }
     }
 
-    fn place_point(&self, canvas: &mut Canvas, point: Point) -> () {
-        canvas.entry(point).or_insert(Vec::new()).push(self.id)
+    fn place_point(&self, canvas: &mut Canvas, point: Point) {
+        canvas.entry(point).or_default().push(self.id)
     }
 
-    fn place(&self, canvas: &mut Canvas) -> () {
+    fn place(&self, canvas: &mut Canvas) {
         let Point {x, y} = self.origin;
         let (width, height) = (self.width, self.height);
 
@@ -102,20 +104,31 @@ fn part_2(claims: &Vec<Claim>, canvas: &Canvas) -> Option<i64> {
     None
 }
 
-fn main() -> Result<(), std::io::Error> {
-    let contents: String = fs::read_to_string("input.txt")?;
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Claim>;
 
-    let mut lines: Vec<String> = Vec::new();
-    for line in contents.split("\n") {
-        if line != "" {
-            lines.push(line.to_string());
+    fn parse(contents: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let mut lines: Vec<String> = Vec::new();
+        for line in contents.split("\n") {
+            if !line.is_empty() {
+                lines.push(line.to_string());
+            };
         };
-    };
 
-    let claims: Vec<Claim> = lines.into_iter().map(|l| Claim::new(&l)).collect();
-    let (canvas, num_overlaps) = part_1(&claims);
-    println!("Part 1: in^2 of fabric overlapped = {}", num_overlaps);
-    println!("Part 2: non-overlapping claim = {}", part_2(&claims, &canvas).unwrap());
+        Ok(lines.into_iter().map(|l| Claim::new(&l)).collect())
+    }
+
+    // in^2 of fabric overlapped
+    fn part1(claims: &Self::Input) -> Answer {
+        let (_, num_overlaps) = part_1(claims);
+        num_overlaps.into()
+    }
 
-    Ok(())
+    // the claim that doesn't overlap any other
+    fn part2(claims: &Self::Input) -> Answer {
+        let (canvas, _) = part_1(claims);
+        part_2(claims, &canvas).unwrap().into()
+    }
 }
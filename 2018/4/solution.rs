@@ -1,9 +1,11 @@
-use std::fs;
+use std::error::Error;
 use std::fmt;
 use std::cmp;
 use std::collections::HashMap;
 use std::ops;
 
+use crate::{Answer, Solution};
+
 #[derive(PartialEq, Eq, Clone, Copy)]
 struct Timestamp {
     year: i64,
@@ -14,7 +16,7 @@ struct Timestamp {
 }
 
 impl Timestamp {
-    fn from_string(s: &String) -> Timestamp {
+    fn from_string(s: &str) -> Timestamp {
         let ts: Vec<i64> = s.split(|c| {
             "[- :]".chars().collect::<Vec<char>>().contains(&c)
         }).filter(|s| s != &"").map(|s| {
@@ -81,7 +83,7 @@ impl fmt::Display for RecordKind {
 }
 
 #[derive(PartialEq, Eq, Clone, Copy)]
-struct Record {
+pub struct Record {
     timestamp: Timestamp,
     guard_id: Option<i64>,
     kind: RecordKind
@@ -94,7 +96,7 @@ impl Record {
                       .collect::<Vec<String>>();
 
         let tokens: Vec<String> = timestamp_tokens[1]
-                                .split(|c| c == ' ' || c == '#')
+                                .split([' ', '#'])
                                 .filter(|s| s != &"")
                                 .map(|s| s.to_string())
                                 .collect();
@@ -110,10 +112,7 @@ impl Record {
 
         let mut guard_id = None;
         if kind == RecordKind::BeginsShift {
-            guard_id = match tokens[1].parse::<i64>() {
-                Ok(i) => Some(i),
-                Err(_) => None
-            };
+            guard_id = tokens[1].parse::<i64>().ok();
         };
 
         Record { timestamp, guard_id, kind }
@@ -221,45 +220,55 @@ fn part_2(records_by_guard: &HashMap<i64, Vec<Record>>) -> (i64, i64) {
     (consistentest_guard.unwrap(), consistentest_minute.unwrap())
 }
 
-fn main() -> Result<(), std::io::Error> {
-    let contents: String = fs::read_to_string("input.txt")?;
+pub struct Day4;
 
-    let mut lines: Vec<String> = Vec::new();
-    for line in contents.split("\n") {
-        if line != "" {
-            lines.push(line.to_string());
+impl Solution for Day4 {
+    type Input = HashMap<i64, Vec<Record>>;
+
+    fn parse(contents: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let mut lines: Vec<String> = Vec::new();
+        for line in contents.split("\n") {
+            if !line.is_empty() {
+                lines.push(line.to_string());
+            };
         };
-    };
 
-    let mut records = lines.iter()
-        .map(Record::from_string)
-        .collect::<Vec<Record>>();
+        let mut records = lines.iter()
+            .map(Record::from_string)
+            .collect::<Vec<Record>>();
 
-    records.sort_unstable();
+        records.sort_unstable();
 
-    let mut records_by_guard: HashMap<i64, Vec<Record>> = HashMap::new();
+        let mut records_by_guard: HashMap<i64, Vec<Record>> = HashMap::new();
 
-    let mut current_guard_id: Option<i64> = None;
-    for mut record in records {
-        match record.guard_id {
-            Some(id) => current_guard_id = Some(id),
-            None => record.guard_id = current_guard_id
-        };
-        match record.kind {
-            RecordKind::BeginsShift => (),
-            _ => records_by_guard
-                    .entry(current_guard_id.unwrap())
-                    .or_insert(Vec::new())
-                    .push(record)
+        let mut current_guard_id: Option<i64> = None;
+        for mut record in records {
+            match record.guard_id {
+                Some(id) => current_guard_id = Some(id),
+                None => record.guard_id = current_guard_id
+            };
+            match record.kind {
+                RecordKind::BeginsShift => (),
+                _ => records_by_guard
+                        .entry(current_guard_id.unwrap())
+                        .or_default()
+                        .push(record)
+            };
         };
-    };
 
-    let (laziest_id, laziest_minute) = part_1(&records_by_guard);
-    println!("Part 1: guard {} was the laziest, most often asleep at minute {}. Answer = {}",
-             laziest_id, laziest_minute, laziest_id * laziest_minute);
+        Ok(records_by_guard)
+    }
+
+    // the laziest guard's id times the minute they were most often asleep
+    fn part1(records_by_guard: &Self::Input) -> Answer {
+        let (laziest_id, laziest_minute) = part_1(records_by_guard);
+        (laziest_id * laziest_minute).into()
+    }
 
-    let (consistentest_id, consistentest_minute) = part_2(&records_by_guard);
-    println!("Part 2: guard {} was the most consistent, most often asleep at minute {}. Answer = {}",
-             consistentest_id, consistentest_minute, consistentest_id * consistentest_minute);
-    Ok(())
+    // the most consistent guard's id times the minute they were most often
+    // asleep
+    fn part2(records_by_guard: &Self::Input) -> Answer {
+        let (consistentest_id, consistentest_minute) = part_2(records_by_guard);
+        (consistentest_id * consistentest_minute).into()
+    }
 }
@@ -1,6 +1,8 @@
-use std::fs;
+use std::error::Error;
 use std::collections::HashSet;
 
+use crate::{Answer, Solution};
+
 fn flip_case(c: &char) -> char {
     ((*c as u8) ^ 0x20) as char
 }
@@ -16,16 +18,14 @@ fn part_1(polymer: &mut Vec<char>) -> u64 {
             // removes the chars that start at i and i + 1
             polymer.remove(i);
             polymer.remove(i);
-            if i != 0 {
-                i -= 1;
-            };
+            i = i.saturating_sub(1);
         } else {
             i += 1;
         };
     };
 }
 
-fn part_2(polymer: &String) -> u64 {
+fn part_2(polymer: &str) -> u64 {
     let mut chars_seen: HashSet<char> = HashSet::new();
     for c in polymer.chars() {
         let _ = chars_seen.insert(c.to_ascii_lowercase());
@@ -39,7 +39,7 @@ fn part_2(polymer: &String) -> u64 {
             .collect();
 
         let len = part_1(&mut cut_polymer.chars().collect());
-        if min == None || len < min.unwrap() {
+        if min.is_none() || len < min.unwrap() {
             min = Some(len);
         };
     };
@@ -47,14 +47,22 @@ fn part_2(polymer: &String) -> u64 {
     min.unwrap()
 }
 
-fn main() -> Result<(), std::io::Error> {
-    let polymer: String = fs::read_to_string("input.txt")?.trim().to_string();
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = String;
 
-    println!("Part 1: reacted length = {}",
-             part_1(&mut polymer.chars().collect()));
+    fn parse(contents: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(contents.trim().to_string())
+    }
 
-    println!("Part 2: min length = {}",
-             part_2(&polymer));
+    // reacted length
+    fn part1(polymer: &Self::Input) -> Answer {
+        part_1(&mut polymer.chars().collect()).into()
+    }
 
-    Ok(())
+    // min length
+    fn part2(polymer: &Self::Input) -> Answer {
+        part_2(polymer).into()
+    }
 }
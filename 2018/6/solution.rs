@@ -0,0 +1,121 @@
+use std::error::Error;
+use std::collections::BTreeMap;
+
+use crate::{Answer, Solution};
+
+fn manhattan_distance((x1, y1): (i64, i64), (x2, y2): (i64, i64)) -> i64 {
+    (x2 - x1).abs() + (y2 - y1).abs()
+}
+
+fn part_1(points: &mut BTreeMap<(i64, i64), Option<i64>>, x_max: i64, y_max: i64) -> i64 {
+    for x in 0..(x_max + 1) {
+        for y in 0..(y_max + 1) {
+            let mut min_distance = None;
+            let mut closest_point = None;
+            for (&point, _) in points.iter() {
+                let distance = manhattan_distance((x, y), point);
+                match min_distance {
+                    Some(min) if distance > min => (),
+                    Some(min) if distance == min => closest_point = None,
+                    _ => {
+                        min_distance = Some(distance);
+                        closest_point = Some(point);
+                    }
+                };
+            };
+
+            if let Some(closest_point) = closest_point {
+                // areas that reach the edge go on forever
+                if [0, x_max].contains(&x) || [0, y_max].contains(&y) {
+                    let _ = points.insert(closest_point, None);
+                };
+
+                let area_size: &mut Option<i64> = points
+                    .get_mut(&closest_point)
+                    .unwrap();
+                match area_size {
+                    None => (),
+                    Some(s) => *s += 1
+                };
+            };
+        };
+    };
+
+    let mut max_area: Option<i64> = None;
+    for area in points.values() {
+        match area {
+            Some(a) if max_area.is_none() || *a > max_area.unwrap() => {
+                max_area = Some(*a)
+            },
+            _ => ()
+        };
+    };
+
+    max_area.unwrap()
+}
+
+fn part_2(points: &BTreeMap<(i64, i64), Option<i64>>, x_max: i64, y_max: i64) -> i64 {
+    let mut region_size = 0;
+    for x in 0..(x_max + 1) {
+        for y in 0..(y_max + 1) {
+            let mut sum_dist = 0;
+            for (&point, _) in points.iter() {
+                sum_dist += manhattan_distance(point, (x, y));
+            };
+            if sum_dist < 10000 {
+                region_size += 1;
+            };
+        };
+    };
+
+    region_size
+}
+
+pub struct Day6;
+
+pub struct Points {
+    points: BTreeMap<(i64, i64), Option<i64>>,
+    x_max: i64,
+    y_max: i64
+}
+
+impl Solution for Day6 {
+    type Input = Points;
+
+    fn parse(contents: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let mut points: BTreeMap<(i64, i64), Option<i64>> = BTreeMap::new();
+
+        let mut x_max = 0;
+        let mut y_max = 0;
+        for line in contents.split("\n") {
+            if !line.is_empty() {
+                let coords: Vec<i64> = line
+                    .split(", ")
+                    .map(|x| x.parse::<i64>().unwrap())
+                    .collect();
+                let (x, y) = (coords[0], coords[1]);
+
+                if x > x_max {
+                    x_max = x;
+                };
+                if y > y_max {
+                    y_max = y;
+                }
+
+                points.insert((x, y), Some(0));
+            };
+        };
+
+        Ok(Points { points, x_max, y_max })
+    }
+
+    // largest non-infinite area
+    fn part1(input: &Self::Input) -> Answer {
+        part_1(&mut input.points.clone(), input.x_max, input.y_max).into()
+    }
+
+    // region containing locations with distance < 10000
+    fn part2(input: &Self::Input) -> Answer {
+        part_2(&input.points, input.x_max, input.y_max).into()
+    }
+}
@@ -1,8 +1,9 @@
-use std::env;
-use std::fs;
+use std::error::Error;
 
+use crate::{Answer, Solution};
 
-struct Node {
+
+pub struct Node {
     num_children: u64,
     num_metadata: u64,
     children: Vec<Node>,
@@ -11,8 +12,8 @@ struct Node {
 
 impl Node {
     fn init(raw: &[u64]) -> Node {
-        let num_children = raw[0] as u64;
-        let num_metadata = raw[1] as u64;
+        let num_children = raw[0];
+        let num_metadata = raw[1];
 
         Node {
             num_children,
@@ -40,9 +41,7 @@ impl Node {
             remaining = new_remaining;
         };
 
-        for i in 0..(node.num_metadata as usize) {
-            node.metadata.push(remaining[i]);
-        };
+        node.metadata.extend_from_slice(&remaining[..(node.num_metadata as usize)]);
 
         remaining = &remaining[(node.num_metadata as usize)..];
 
@@ -58,11 +57,11 @@ impl Node {
 fn part_1(tree: &Node) -> u64 {
     let mut count: u64 = tree.metadata
         .iter()
-        .fold(0, |acc, x| acc + x);
+        .sum();
 
     count += tree.children
         .iter()
-        .fold(0, |acc, child| acc + part_1(&child));
+        .fold(0, |acc, child| acc + part_1(child));
 
     count
 }
@@ -71,7 +70,7 @@ fn part_2(tree: &Node) -> u64 {
     if tree.num_children == 0 {
         tree.metadata
             .iter()
-            .fold(0, |acc, x| acc + x)
+            .sum()
     }
     else {
         tree.metadata
@@ -86,24 +85,32 @@ fn part_2(tree: &Node) -> u64 {
     }
 }
 
-fn main() -> Result<(), std::io::Error> {
-    let args: Vec<String> = env::args().collect();
-    let contents: String = fs::read_to_string(&args[1])?;
+pub struct Day8;
 
-    let raw: Vec<u64> = contents
-        .split_whitespace()
-        .map(|s| {
-            match s.parse::<u64>() {
-                Ok(i) => i,
-                Err(_) => panic!("Couldn't parse file")
-            }
-        })
-        .collect();
+impl Solution for Day8 {
+    type Input = Node;
+
+    fn parse(contents: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let raw: Vec<u64> = contents
+            .split_whitespace()
+            .map(|s| {
+                match s.parse::<u64>() {
+                    Ok(i) => i,
+                    Err(_) => panic!("Couldn't parse file")
+                }
+            })
+            .collect();
 
-    let tree = Node::from_raw(raw).unwrap();
+        Ok(Node::from_raw(raw).unwrap())
+    }
 
-    println!("Part 1: sum of all metadata entries = {}", part_1(&tree));
-    println!("Part 2: value of root node = {}", part_2(&tree));
+    // sum of all metadata entries
+    fn part1(tree: &Self::Input) -> Answer {
+        part_1(tree).into()
+    }
 
-    Ok(())
+    // value of root node
+    fn part2(tree: &Self::Input) -> Answer {
+        part_2(tree).into()
+    }
 }
@@ -1,6 +1,8 @@
-use std::{env, fmt};
+use std::{error::Error, fmt};
 use std::collections::VecDeque;
 
+use crate::{Answer, Solution};
+
 struct Circle<T> {
     current: Option<T>,
     left: VecDeque<T>,
@@ -28,35 +30,35 @@ impl<T: std::fmt::Debug> Circle<T> {
         let ret = self.current.take();
 
         // take from right
-        self.right.pop_front().map(|e| self.current = Some(e));
+        if let Some(e) = self.right.pop_front() { self.current = Some(e) }
 
         ret
     }
 
     fn rotate_clockwise(&mut self) {
         // push onto left
-        self.current.take().map(|e| self.left.push_back(e));
+        if let Some(e) = self.current.take() { self.left.push_back(e) }
 
         // rotate the lists
         if self.left.len() > self.right.len() {
-            self.left.pop_front().map(|e| self.right.push_back(e));
+            if let Some(e) = self.left.pop_front() { self.right.push_back(e) }
         }
 
         // take from right
-        self.right.pop_front().map(|e| self.current = Some(e));
+        if let Some(e) = self.right.pop_front() { self.current = Some(e) }
     }
 
     fn rotate_counterclockwise(&mut self) {
         // push onto right
-        self.current.take().map(|e| self.right.push_front(e));
+        if let Some(e) = self.current.take() { self.right.push_front(e) }
 
         // rotate the lists
         if self.right.len() > self.left.len() {
-            self.right.pop_back().map(|e| self.left.push_front(e));
+            if let Some(e) = self.right.pop_back() { self.left.push_front(e) }
         };
 
         // take from left
-        self.left.pop_back().map(|e| self.current = Some(e));
+        if let Some(e) = self.left.pop_back() { self.current = Some(e) }
     }
 
     // positive num_rot goes clockwise, negative num_rot goes counterclockwise
@@ -119,21 +121,37 @@ fn play(num_players: usize, num_marbles: u64) -> u64 {
     scores.iter().max().cloned().unwrap()
 }
 
-fn main() -> Result<(), std::io::Error> {
-    let args: Vec<String> = env::args().collect();
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = (usize, u64);
 
-    let num_players = args[1].parse::<usize>().unwrap();
-    let num_marbles = args[2].parse::<u64>().unwrap();
+    // the input is a sentence, "N players; last marble is worth M points", of
+    // which only the numbers matter
+    fn parse(contents: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let mut numbers = contents
+            .split_whitespace()
+            .filter_map(|word| word.trim_end_matches(';').parse::<u64>().ok());
 
-    println!("Part 1: highest score = {}", play(num_players, num_marbles));
-    println!("Part 2: highest score = {}", play(num_players, num_marbles * 100));
+        let num_players = numbers.next().ok_or("No player count!")? as usize;
+        let num_marbles = numbers.next().ok_or("No marble count!")?;
+        Ok((num_players, num_marbles))
+    }
 
-    Ok(())
+    // highest score
+    fn part1(&(num_players, num_marbles): &Self::Input) -> Answer {
+        play(num_players, num_marbles).into()
+    }
+
+    // highest score, with a hundred times the marbles
+    fn part2(&(num_players, num_marbles): &Self::Input) -> Answer {
+        play(num_players, num_marbles * 100).into()
+    }
 }
 
 #[test]
 fn creation() {
-    let mut circle: Circle<String> = Circle::new();
+    let circle: Circle<String> = Circle::new();
     assert!(circle.current.is_none());
     assert!(circle.left.is_empty());
     assert!(circle.right.is_empty());
@@ -1,5 +1,4 @@
-use std::env;
-use std::fs;
+use std::error::Error;
 
 use std::fmt;
 use std::cmp::{PartialEq, Eq};
@@ -7,6 +6,8 @@ use std::hash::Hash;
 use std::collections::HashMap;
 use std::f64::consts::{PI, FRAC_PI_2};
 
+use crate::{Answer, Solution};
+
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 struct Point {
@@ -71,13 +72,15 @@ impl Point {
 
 type Space = Vec<Vec<bool>>;
 
-fn print_space(space: &Space) -> () {
+// for debugging
+#[allow(dead_code)]
+fn print_space(space: &Space) {
     space.iter().for_each(|row| {
             row.iter().for_each(|&point| {
                 let pretty = if point { '#' } else { '.' };
                 print!("{}", pretty);
             });
-            println!("");
+            println!();
         });
 }
 
@@ -123,7 +126,7 @@ fn optimal_station(space: &Space) -> (Point, usize) {
     space
         .iter()
         .enumerate()
-        .map(|(y, row)| row
+        .flat_map(|(y, row)| row
              .iter()
              .enumerate()
              .filter(|(_, &loc)| loc)
@@ -131,8 +134,7 @@ fn optimal_station(space: &Space) -> (Point, usize) {
                  let p1 = Point { x: x as i64, y: y as i64 };
                  (p1, count_visible(space, &p1))
              }))
-        .flatten()
-        .max_by(|(_, count1), (_, count2)| count1.cmp(&count2))
+        .max_by(|(_, count1), (_, count2)| count1.cmp(count2))
         .expect("No maximum point!")
 }
 
@@ -143,8 +145,8 @@ fn scan(space: &Space) -> Vec<Point> {
         .iter_mut()
         .for_each(|points| points
                   .sort_by(|point1, point2|
-                           origin.distance(&point1).partial_cmp(
-                               &origin.distance(&point2))
+                           origin.distance(point1).partial_cmp(
+                               &origin.distance(point2))
                            .expect("No ordering on distance found!")
                   )
         );
@@ -167,64 +169,35 @@ fn scan(space: &Space) -> Vec<Point> {
 }
 
 
-fn part1(space: &Space) -> () {
-    let (max_point, max_count) = optimal_station(space);
-
-    println!("Part 1: {:?} can see {} asteroids", max_point, max_count);
-}
-
-fn part2(space: &Space) -> () {
-    let lucky = scan(space)[199];
-    println!("{}", lucky.x * 100 + lucky.y);
-}
-
+pub struct Day10;
 
-fn main() -> Result<(), std::io::Error> {
-    let args: Vec<String> = env::args().collect();
-    let contents: String = fs::read_to_string(&args[1])?;
+impl Solution for Day10 {
+    type Input = Space;
 
-    let mut space: Space = Vec::new();
-    for line in contents.split("\n") {
-        if line != "" {
-            space.push(line.chars().map(|c| match c {
-                '.' => false,
-                '#' => true,
-                _ => panic!("Unexpected input {}", c)
-            }).collect());
+    fn parse(contents: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let mut space: Space = Vec::new();
+        for line in contents.split("\n") {
+            if !line.is_empty() {
+                space.push(line.chars().map(|c| match c {
+                    '.' => false,
+                    '#' => true,
+                    _ => panic!("Unexpected input {}", c)
+                }).collect());
+            };
         };
-    };
 
-    // let p1 = Point { x: 6, y: 2 };
-    // let p2 = Point { x: 9, y: 3 };
-    // let line = Line::from_points(&p1, &p2);
-    // println!("{:?}", line);
-    // println!("{}", line.plug_chug(3));
-    // println!("{}", can_see_each_other(&space, &p1, &p2));
-
-    // let p3 = Point { x: 1, y: 3 };
-    // let p4 = Point { x: 2, y: 6 };
-    // println!("{}", can_see_each_other(&space, &p3, &p4));
-
-    // let p1 = Point { x: 0, y: 0 };
-    // let p2 = Point { x: 1, y: 3 };
-    // let p3 = Point { x: 1, y: 6 };
-    // let p4 = Point { x: 1, y: 9 };
-    // println!("{:?}", p4.point_diff(&p2).normalize());
-
-    print_space(&space);
-    part1(&space);
-
-    let unit_vecs: Vec<Point> = vec![
-		Point { x: 0, y: -1 }, Point { x: 1, y: -1 },
-		Point { x: 1, y: 0 }, Point { x: 1, y: 1 },
-        Point { x: 0, y: 1 }, Point { x: -1, y: 1 },
-	    Point { x: -1, y: 0 }, Point { x: -1, y: -1000 }
-	];
-    for (p, dir) in unit_vecs.iter().zip(vec!["U", "UR", "R", "RD", "D", "DL", "L", "LU"]) {
-        println!("{}: {:?}, {:?}", dir, p, p.scan_angle());
-    };
+        Ok(space)
+    }
 
-    part2(&space);
+    // how many asteroids the best place for a station can see
+    fn part1(space: &Self::Input) -> Answer {
+        let (_, max_count) = optimal_station(space);
+        max_count.into()
+    }
 
-    Ok(())
+    // where the 200th asteroid to be vaporized is
+    fn part2(space: &Self::Input) -> Answer {
+        let lucky = scan(space)[199];
+        (lucky.x * 100 + lucky.y).into()
+    }
 }
@@ -0,0 +1,308 @@
+use std::error::Error;
+
+use std::hash::Hash;
+use std::cmp::{PartialEq, Eq};
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+
+use intcode::{run, Framing, Listing, Node, State, Tape};
+
+use log::debug;
+use screen::Screen as Terminal;
+use std::{thread, time};
+
+use crate::{Answer, Solution};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Direction {
+    Left = 0,
+    Right = 1,
+    Up = 2,
+    Down = 3
+}
+
+impl Direction {
+    fn turn_left(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down
+        }
+    }
+
+    fn turn_right(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up
+        }
+    }
+}
+
+fn advance(loc: (i64, i64), dir: Direction) -> (i64, i64) {
+    let (x, y) = loc;
+    match dir {
+        Direction::Up => (x, y - 1),
+        Direction::Right => (x + 1, y),
+        Direction::Down => (x, y + 1),
+        Direction::Left => (x - 1, y)
+    }
+}
+
+type Hull = HashMap<(i64, i64), Vec<i64>>;
+
+fn paint_at(hull: &mut Hull, loc: (i64, i64), paint: i64) {
+    hull
+        .entry(loc)
+        .or_default()
+        .push(paint);
+}
+
+// the bot's outputs come in pairs: a color to paint, then which way to turn
+enum Turn {
+    Left,
+    Right
+}
+
+struct Order {
+    paint: i64,
+    turn: Turn
+}
+
+fn orders() -> Framing<Order> {
+    Framing::new(2, |frame| {
+        let turn = match frame[1] {
+            0 => Turn::Left,
+            1 => Turn::Right,
+            turn => panic!("Unknown turn direction {}", turn)
+        };
+        Order { paint: frame[0], turn }
+    })
+}
+
+// one paint-and-turn from the bot: where it painted, what it painted, and
+// which way it faced afterwards
+struct Event {
+    step: usize,
+    loc: (i64, i64),
+    dir: Direction,
+    paint: i64
+}
+
+type Timeline = Vec<Event>;
+
+// `record` is where to save a tape of the run, if anywhere
+fn execute(listing: &Listing, mut starting_panel: Option<i64>, record: Option<&str>) -> (Hull, (i64, i64), Direction, Timeline) {
+    let mut hull: Hull = HashMap::new();
+    let mut timeline: Timeline = Vec::new();
+    let (mut bot_x, mut bot_y) = (0, 0);
+    let mut bot_direction = Direction::Up;
+    let mut bot = Node::new('B', &listing.program);
+    listing.header.apply(&mut bot);
+    if record.is_some() {
+        bot.tape = Some(Tape::start(&listing.program, &bot));
+    };
+    let framing = orders();
+
+    while bot.state != State::Halted {
+        // run bot until either input needed or output given
+        debug!("BEGIN RUN: {:?}", bot);
+        run(&mut bot);
+        debug!("END RUN: {:?}", bot);
+        if let Some(fault) = &bot.fault {
+            panic!("Robot faulted: {}", fault);
+        };
+
+        for Order { paint, turn } in framing.take(&mut bot).unwrap_or_else(|e| panic!("{}", e)) {
+            paint_at(&mut hull, (bot_x, bot_y), paint);
+            bot_direction = match turn {
+                Turn::Left => bot_direction.turn_left(),
+                Turn::Right => bot_direction.turn_right()
+            };
+            timeline.push(Event {
+                step: timeline.len(),
+                loc: (bot_x, bot_y),
+                dir: bot_direction,
+                paint
+            });
+            let (new_x, new_y) = advance((bot_x, bot_y), bot_direction);
+            bot_x = new_x;
+            bot_y = new_y;
+        };
+
+        if bot.state == State::InputWait {
+            bot.input.push_back(
+                starting_panel.take().unwrap_or(
+                    *(
+                        hull
+                            .entry((bot_x, bot_y))
+                            .or_default()
+                            .last()
+                            .unwrap_or(&0)
+                     )
+                )
+            );
+        };
+    }
+
+    if let Some(path) = record {
+        bot.tape.take().expect("No tape recorded!").save(path).expect("Couldn't save tape!");
+    };
+
+    (hull, (bot_x, bot_y), bot_direction, timeline)
+}
+
+fn bounds(hull: &Hull) -> ((i64, i64), (i64, i64)) {
+    screen::bounds(hull.keys()).expect("Nothing painted!")
+}
+
+// one character per panel, so the terminal renderer can tell where each one is
+fn render(hull: &Hull, bot_loc: (i64, i64), bot_dir: Direction, bounds: ((i64, i64), (i64, i64))) -> String {
+    let (bot_x, bot_y) = bot_loc;
+    let ((min_x, min_y), (max_x, max_y)) = bounds;
+
+    let mut grid = String::new();
+    for y in min_y..=max_y {
+        for x in min_x..=max_x {
+            if x == bot_x && y == bot_y {
+                match bot_dir {
+                    Direction::Up => grid.push('^'),
+                    Direction::Right => grid.push('>'),
+                    Direction::Down => grid.push('v'),
+                    Direction::Left => grid.push('<')
+                };
+            } else {
+                let paint = match hull.get(&(x, y)) {
+                    Some(instructions) => *instructions.last().expect("Expected paint but found none"),
+                    None => 0
+                };
+                match paint {
+                    0 => grid.push('.'),
+                    1 => grid.push('#'),
+                    _ => panic!("Unknown paint {}", paint)
+                };
+            };
+        }
+        grid.push('\n');
+    };
+
+    grid
+}
+
+fn visualize(hull: &Hull, bot_loc: (i64, i64), bot_dir: Direction) {
+    crate::detail(&render(hull, bot_loc, bot_dir, bounds(hull)));
+}
+
+// redraws the hull after every `every`th event, within the final bounds so
+// the picture doesn't shift around as the bot wanders
+fn replay(timeline: &Timeline, every: usize, animate: bool) {
+    let mut hull: Hull = HashMap::new();
+    for event in timeline {
+        paint_at(&mut hull, event.loc, event.paint);
+    };
+    let final_bounds = bounds(&hull);
+    hull.clear();
+
+    let mut terminal = if animate {
+        Some(Terminal::new().expect("Couldn't set up the terminal!"))
+    } else { None };
+    for event in timeline {
+        paint_at(&mut hull, event.loc, event.paint);
+        if event.step % every != 0 && event.step + 1 != timeline.len() {
+            continue;
+        };
+
+        let status = format!("Step {}: painted {} at {:?}, now facing {:?}",
+                             event.step, event.paint, event.loc, event.dir);
+        let frame = render(&hull, advance(event.loc, event.dir), event.dir, final_bounds);
+        match &mut terminal {
+            Some(terminal) => {
+                terminal.draw(&frame, &status).expect("Couldn't draw the hull!");
+                thread::sleep(time::Duration::from_millis(50));
+            },
+            None => crate::detail(&format!("{}\n{}\n", status, frame))
+        };
+    };
+}
+
+
+// how the painting gets replayed after part 2, if at all
+enum Replay {
+    Animate,
+    Frames(NonZeroUsize)
+}
+
+pub struct Robot {
+    listing: Listing,
+    replay: Option<Replay>,
+    // where to save a tape of part 2, if anywhere
+    record: Option<String>
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Robot;
+
+    fn parse(contents: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(Robot { listing: intcode::parse(contents)?, replay: None, record: None })
+    }
+
+    // usage: aoc run 2019 11 [-- [--replay | --frames <n>] [--record <part 2 tape>]]
+    fn configure(robot: &mut Self::Input, args: Vec<String>) -> Result<(), Box<dyn Error>> {
+        let (mut args, setup) = intcode::options(args)?;
+        robot.listing.header.merge(setup);
+        robot.record = intcode::take_option(&mut args, "--record")?;
+        if let Some(every) = intcode::take_option(&mut args, "--frames")? {
+            let every = every.parse().map_err(|_| format!("--frames needs a number above 0, not {}", every))?;
+            robot.replay = Some(Replay::Frames(every));
+        };
+        if let Some(i) = args.iter().position(|arg| arg == "--replay") {
+            args.remove(i);
+            robot.replay = Some(Replay::Animate);
+        };
+        crate::reject_options(&args)
+    }
+
+    // how many panels get painted at least once
+    fn part1(robot: &Self::Input) -> Answer {
+        let (hull, _, _, _) = execute(&robot.listing, None, None);
+        hull.keys().count().into()
+    }
+
+    // the registration identifier, painted starting from a white panel. if it
+    // can't be read, the hull gets drawn so someone can have a look
+    fn part2(robot: &Self::Input) -> Answer {
+        let (hull, bot_loc, bot_dir, timeline) = execute(&robot.listing, Some(1), robot.record.as_deref());
+
+        let white = |x: i64, y: i64| hull
+            .get(&(x, y))
+            .and_then(|instructions| instructions.last())
+            .is_some_and(|&paint| paint == 1);
+        let ((min_x, min_y), (max_x, max_y)) = bounds(&hull);
+        let grid = (min_y..=max_y)
+            .map(|y| (min_x..=max_x).map(|x| white(x, y)).collect())
+            .collect::<Vec<Vec<bool>>>();
+
+        let identifier = match ocr::recognize(&grid) {
+            Ok(identifier) => identifier.into(),
+            Err(e) => {
+                crate::detail(&format!("Couldn't read the identifier: {}\n", e));
+                visualize(&hull, bot_loc, bot_dir);
+                Answer::Unsolved
+            }
+        };
+
+        // with drawing switched off, an animation comes out as every frame
+        match robot.replay {
+            Some(Replay::Animate) => replay(&timeline, 1, crate::animate()),
+            Some(Replay::Frames(every)) => replay(&timeline, every.get(), false),
+            None => ()
+        };
+
+        identifier
+    }
+}
@@ -1,11 +1,12 @@
-use std::env;
-use std::fs;
+use std::error::Error;
 
 use std::fmt;
 use std::cmp::{Ordering, PartialEq, Eq};
 use std::hash::Hash;
 use std::collections::HashSet;
 
+use crate::{Answer, Solution};
+
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 struct Vector {
@@ -21,7 +22,7 @@ impl Vector {
                 .chars()
                 .collect::<Vec<_>>()
                 .contains(&c))
-            .filter(|&tok| tok != "" && tok != " ")
+            .filter(|&tok| !tok.is_empty() && tok != " ")
             .map(|tok| tok.parse::<i64>().expect("Couldn't parse i64!"));
 
         let x = toks.next().expect("No x component!");
@@ -53,7 +54,7 @@ impl fmt::Debug for Vector {
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
-struct Moon {
+pub struct Moon {
     pos: Vector,
     vel: Vector
 }
@@ -93,7 +94,7 @@ fn vel_diff(v1: &Vector, v2: &Vector) -> (Vector, Vector) {
     (v1_diff, v2_diff)
 }
 
-fn step(moons: &mut Vec<Moon>) -> () {
+fn step(moons: &mut [Moon]) {
     let mut velocities: Vec<Vector> = moons.iter().map(|m| m.vel).collect();
     // apply gravity: compute velocities
     for (i1, m1) in moons.iter().enumerate() {
@@ -105,13 +106,13 @@ fn step(moons: &mut Vec<Moon>) -> () {
     };
 
     // apply velocity: change positions
-    for (mut m, v_diff) in moons.iter_mut().zip(velocities.iter()) {
+    for (m, v_diff) in moons.iter_mut().zip(velocities.iter()) {
         m.vel = *v_diff;
-        m.pos = m.pos.sum(&v_diff);
+        m.pos = m.pos.sum(v_diff);
     };
 }
 
-fn compute_energy(moons: &Vec<Moon>) -> u64 {
+fn compute_energy(moons: &[Moon]) -> u64 {
     moons
         .iter()
         .map(|m| m.pos.energy() * m.vel.energy())
@@ -133,14 +134,17 @@ fn lcm(x: usize, y: usize) -> usize {
 }
 
 
-fn part1(moons: &Vec<Moon>) -> () {
+fn part1(moons: &[Moon]) -> u64 {
     let mut moons = moons.to_vec();
     (0..1000).for_each(|_| step(&mut moons));
-    println!("Part 1: {:?}", compute_energy(&moons));
+    compute_energy(&moons)
 }
 
-fn part2(moons: &Vec<Moon>) -> () {
-    let selectors: Vec<fn(&Moon) -> (i64, i64)> = vec![
+// one axis of a moon's position and velocity
+type Selector = fn(&Moon) -> (i64, i64);
+
+fn part2(moons: &[Moon]) -> usize {
+    let selectors: Vec<Selector> = vec![
         |m| (m.pos.x, m.vel.x),
         |m| (m.pos.y, m.vel.y),
         |m| (m.pos.z, m.vel.z),
@@ -160,27 +164,35 @@ fn part2(moons: &Vec<Moon>) -> () {
         cycle_frequencies.push(cycle_frequency);
     };
 
-    let cycle_point = cycle_frequencies.iter().fold(1, |acc, &freq| lcm(acc, freq));
-
-    println!("Part 2: {}", cycle_point);
+    cycle_frequencies.iter().fold(1, |acc, &freq| lcm(acc, freq))
 }
 
 
-fn main() -> Result<(), std::io::Error> {
-    let args: Vec<String> = env::args().collect();
-    let contents: String = fs::read_to_string(&args[1])?;
+pub struct Day12;
 
-    let mut moons: Vec<Moon> = Vec::new();
-    for line in contents.split("\n") {
-        if line != "" {
-            let pos = Vector::from_str(line);
-            let moon = Moon::from_pos(pos);
-            moons.push(moon);
+impl Solution for Day12 {
+    type Input = Vec<Moon>;
+
+    fn parse(contents: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let mut moons: Vec<Moon> = Vec::new();
+        for line in contents.split("\n") {
+            if !line.is_empty() {
+                let pos = Vector::from_str(line);
+                let moon = Moon::from_pos(pos);
+                moons.push(moon);
+            };
         };
-    };
 
-    part1(&moons);
-    part2(&moons);
+        Ok(moons)
+    }
+
+    // total energy after 1000 steps
+    fn part1(moons: &Self::Input) -> Answer {
+        part1(moons).into()
+    }
 
-    Ok(())
+    // steps until the moons are back where they started
+    fn part2(moons: &Self::Input) -> Answer {
+        part2(moons).into()
+    }
 }
@@ -0,0 +1,262 @@
+use std::error::Error;
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use intcode::{run, Framing, Listing, Node, State, Tape};
+use intcode::scan::{Filter, Freezer, Scanner};
+
+use log::debug;
+use screen::Screen as Terminal;
+use std::{thread, time};
+
+use crate::{Answer, Solution};
+
+
+type Screen = HashMap<(i64, i64), i64>;
+
+// the cabinet draws in (x, y, tile) triples, except that (-1, 0) is the score
+enum Event {
+    Tile { x: i64, y: i64, kind: i64 },
+    Score(i64)
+}
+
+fn events() -> Framing<Event> {
+    Framing::new(3, |frame| match *frame {
+        [-1, 0, score] => Event::Score(score),
+        [x, y, kind] => Event::Tile { x, y, kind },
+        _ => unreachable!()
+    })
+}
+
+fn place_tile(screen: &mut Screen, loc: (i64, i64), tile: i64) {
+    screen.insert(loc, tile);
+}
+
+fn render(screen: &Screen) -> String {
+    let (_, (max_x, max_y)) = screen::bounds(screen.keys()).unwrap_or(((0, 0), (0, 0)));
+
+    let mut grid = String::new();
+    for y in 0..=max_y {
+        for x in 0..=max_x {
+            let tile = screen.get(&(x, y)).unwrap_or(&0);
+            match tile {
+                0 => grid.push(' '),
+                1 => grid.push('|'),
+                2 => grid.push('X'),
+                3 => grid.push('-'),
+                4 => grid.push('.'),
+                _ => panic!("Unknown tile type {}", tile)
+            };
+        }
+        grid.push('\n');
+    };
+
+    grid
+}
+
+fn visualize(terminal: &mut Terminal, screen: &Screen, score: i64) {
+    terminal
+        .draw(&render(screen), &format!("SCORE: {}", score))
+        .expect("Couldn't draw the screen!");
+}
+
+fn find_tile(screen: &Screen, tile: i64) -> Option<(i64, i64)> {
+    screen
+        .iter()
+        .find(|(_, &val)| val == tile)
+        .map(|(&loc, _)| loc)
+}
+
+// `on_input` gets a look at the cabinet every time it asks for the joystick,
+// and can pick the input itself instead of chasing the ball. `record` is where
+// to save a tape of the game, if anywhere
+fn execute<F>(listing: &Listing, num_quarters: Option<i64>, record: Option<&str>, mut on_input: F) -> (Screen, i64)
+    where F: FnMut(&mut Node, &Screen, i64) -> Option<i64>
+{
+    let mut screen: Screen = HashMap::new();
+
+    let mut cabinet = Node::new('C', &listing.program);
+    if let Some(quarters) = num_quarters {
+        cabinet.set(0, quarters);
+    };
+    listing.header.apply(&mut cabinet);
+    if record.is_some() {
+        cabinet.tape = Some(Tape::start(&listing.program, &cabinet));
+    };
+
+    let framing = events();
+    let mut score = 0;
+    let mut terminal = if crate::animate() {
+        Some(Terminal::new().expect("Couldn't set up the terminal!"))
+    } else { None };
+
+    while cabinet.state != State::Halted {
+        debug!("BEGIN RUN: {:?}", cabinet);
+        run(&mut cabinet);
+        debug!("END RUN: {:?}", cabinet);
+        if let Some(fault) = &cabinet.fault {
+            panic!("Cabinet faulted: {}", fault);
+        };
+
+        for event in framing.take(&mut cabinet).unwrap_or_else(|e| panic!("{}", e)) {
+            match event {
+                Event::Tile { x, y, kind } => place_tile(&mut screen, (x, y), kind),
+                Event::Score(points) => score = points
+            };
+        };
+
+        if cabinet.state == State::InputWait {
+            let joystick = on_input(&mut cabinet, &screen, score).unwrap_or_else(|| {
+                let (ball_x, _) = find_tile(&screen, 4).expect("No ball found!");
+                let (paddle_x, _) = find_tile(&screen, 3).expect("No paddle found!");
+                match ball_x.cmp(&paddle_x) {
+                    Ordering::Less => -1,
+                    Ordering::Equal => 0,
+                    Ordering::Greater => 1
+                }
+            });
+            cabinet.input.push_back(joystick);
+            if let Some(terminal) = &mut terminal {
+                visualize(terminal, &screen, score);
+                thread::sleep(time::Duration::from_millis(15));
+            };
+        };
+    }
+
+    if let Some(terminal) = &mut terminal {
+        visualize(terminal, &screen, score);
+    };
+
+    if let Some(path) = record {
+        cabinet.tape.take().expect("No tape recorded!").save(path).expect("Couldn't save tape!");
+    };
+
+    (screen, score)
+}
+
+
+// plays part 2 while narrowing down which cells hold the ball's x, the
+// paddle's x and the score. scanning only reads memory, so this can be
+// recorded like any other game
+fn scan(listing: &Listing, record: Option<&str>) -> i64 {
+    let mut scanners: Option<Vec<Scanner>> = None;
+    let (_, score) = execute(listing, Some(2), record, |cabinet, screen, score| {
+        let scanners = scanners.get_or_insert_with(|| vec!(Scanner::new(cabinet); 3));
+        let (ball_x, _) = find_tile(screen, 4).expect("No ball found!");
+        let (paddle_x, _) = find_tile(screen, 3).expect("No paddle found!");
+        scanners[0].filter(cabinet, &Filter::Equal(ball_x));
+        scanners[1].filter(cabinet, &Filter::Equal(paddle_x));
+        scanners[2].filter(cabinet, &Filter::Equal(score));
+        None
+    });
+
+    let scanners = scanners.expect("Cabinet never asked for input!");
+    crate::detail(&format!(
+        "Ball x: {:?}\nPaddle x: {:?}\nScore: {:?}\n",
+        scanners[0].candidates(), scanners[1].candidates(), scanners[2].candidates()
+    ));
+    score
+}
+
+// plays part 2 with some cells held in place and the joystick left alone.
+// collisions are checked against the screen grid in memory rather than the
+// paddle's x, so the way to pin the paddle under the ball is to fill its whole
+// row with paddle; for my input the grid is 44 wide from 639, so that's
+// `--cheat 1432-1473=3`
+fn cheat(listing: &Listing, frozen: &[(usize, i64)]) -> i64 {
+    let mut freezer = Freezer::new();
+    for &(addr, value) in frozen {
+        freezer.freeze(addr, value);
+    };
+    let (_, score) = execute(listing, Some(2), None, |cabinet, _, _| {
+        freezer.apply(cabinet);
+        Some(0)
+    });
+    score
+}
+
+// parses `<addr>[-<addr>]=<value>,...`
+fn frozen_cells(cells: &str) -> Result<Vec<(usize, i64)>, Box<dyn Error>> {
+    let mut frozen = Vec::new();
+    for cell in cells.split(',') {
+        let (addrs, value) = cell.split_once('=').ok_or_else(|| format!("Expected <addr>=<value>, not {}", cell))?;
+        let value: i64 = value.parse().map_err(|_| format!("Bad value {} in {}", value, cell))?;
+        let address = |a: &str| a.parse::<usize>().map_err(|_| format!("Bad address {} in {}", a, cell));
+        let (start, end) = match addrs.split_once('-') {
+            Some((start, end)) => (address(start)?, address(end)?),
+            None => (address(addrs)?, address(addrs)?)
+        };
+        if end < start {
+            return Err(format!("Addresses run backwards in {}", cell).into());
+        };
+        frozen.extend((start..=end).map(|addr| (addr, value)));
+    };
+    Ok(frozen)
+}
+
+// how part 2 gets played
+enum Mode {
+    Play,
+    Scan,
+    Cheat(Vec<(usize, i64)>)
+}
+
+pub struct Cabinet {
+    listing: Listing,
+    mode: Mode,
+    // where to save a tape of part 2, if anywhere
+    record: Option<String>
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Cabinet;
+
+    fn parse(contents: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(Cabinet { listing: intcode::parse(contents)?, mode: Mode::Play, record: None })
+    }
+
+    // usage: aoc run 2019 13 [-- [--scan] [--record <part 2 tape>]]
+    //        aoc run 2019 13 -- --cheat <addr>[-<addr>]=<value>,...
+    fn configure(cabinet: &mut Self::Input, args: Vec<String>) -> Result<(), Box<dyn Error>> {
+        let (mut args, setup) = intcode::options(args)?;
+        cabinet.listing.header.merge(setup);
+        cabinet.record = intcode::take_option(&mut args, "--record")?;
+        if let Some(cells) = intcode::take_option(&mut args, "--cheat")? {
+            // a tape can't hold the cells held in place, so it wouldn't replay
+            if cabinet.record.is_some() {
+                return Err("--record can't be used with --cheat".into());
+            };
+            cabinet.mode = Mode::Cheat(frozen_cells(&cells)?);
+        };
+        if let Some(i) = args.iter().position(|arg| arg == "--scan") {
+            if let Mode::Cheat(_) = cabinet.mode {
+                return Err("Only one of --scan and --cheat".into());
+            };
+            args.remove(i);
+            cabinet.mode = Mode::Scan;
+        };
+        crate::reject_options(&args)
+    }
+
+    // how many blocks are on the screen when the game starts
+    fn part1(cabinet: &Self::Input) -> Answer {
+        let (screen, _) = execute(&cabinet.listing, None, None, |_, _, _| None);
+        screen.values().filter(|&&v| v == 2).count().into()
+    }
+
+    // the score once the last block is broken
+    fn part2(cabinet: &Self::Input) -> Answer {
+        let listing = &cabinet.listing;
+        match &cabinet.mode {
+            Mode::Play => {
+                let (_, score) = execute(listing, Some(2), cabinet.record.as_deref(), |_, _, _| None);
+                score
+            },
+            Mode::Scan => scan(listing, cabinet.record.as_deref()),
+            Mode::Cheat(frozen) => cheat(listing, frozen)
+        }.into()
+    }
+}
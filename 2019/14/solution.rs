@@ -1,15 +1,14 @@
-use std::env;
-use std::fs;
+use std::error::Error;
 
 use std::fmt;
 use std::cmp::{PartialEq, Eq};
 use std::hash::{Hash, Hasher};
 use std::collections::{HashSet, HashMap};
 
-use id_tree::{Tree, Node, NodeId};
-use id_tree::InsertBehavior;
 use log::debug;
 
+use crate::{Answer, Solution};
+
 #[derive(Clone)]
 struct Chemical {
     qty: usize,
@@ -53,7 +52,7 @@ impl Hash for Chemical {
 }
 
 #[derive(Clone)]
-struct Reaction {
+pub struct Reaction {
     output: Chemical,
     inputs: Vec<Chemical>
 }
@@ -71,8 +70,8 @@ impl Reaction {
 
     fn from_str(parts: &str) -> Self {
         let mut toks = parts
-            .split(|c| c == ',' || c == '=' || c == '>')
-            .filter(|&s| s != "")
+            .split([',', '=', '>'])
+            .filter(|&s| !s.is_empty())
             .map(|s| s.trim())
             .collect::<Vec<_>>();
         let output = Chemical::from_str(toks.pop().expect("No output token!"));
@@ -120,26 +119,22 @@ fn multiples_needed(orig_batch: usize, needed: usize) -> usize {
     counter
 }
 
-fn build_reaction_tree(reactions: &HashSet<Reaction>) -> Tree<Chemical> {
-    let mut reaction_tree: Tree<Chemical> = Tree::new();
+// every chemical that goes into the fuel, each pointing at the one it's used
+// to make; the fuel itself is first, with no parent
+type Tree = Vec<(Chemical, Option<usize>)>;
 
-    let root_reaction = reactions.get(&Reaction::from_str("0 FUEL")).expect("No fuel producer!");
-    let root_node_id = reaction_tree
-        .insert(
-            Node::new(root_reaction.output.clone()),
-            InsertBehavior::AsRoot)
-        .expect("Couldn't insert root!");
-    let mut node_stack: Vec<(NodeId, Reaction, usize)> = vec![(root_node_id, root_reaction.clone(), root_reaction.output.qty)];
+fn build_reaction_tree(reactions: &HashSet<Reaction>) -> Tree {
+    let mut reaction_tree: Tree = Vec::new();
 
-    while !node_stack.is_empty() {
-        let (current_node_id, current_reaction, scaling_factor) = node_stack.pop().expect("Node stack was empty!");
+    let root_reaction = reactions.get(&Reaction::from_str("0 FUEL")).expect("No fuel producer!");
+    reaction_tree.push((root_reaction.output.clone(), None));
+    let root_node_id = 0;
+    let mut node_stack: Vec<(usize, Reaction, usize)> = vec![(root_node_id, root_reaction.clone(), root_reaction.output.qty)];
 
+    while let Some((current_node_id, current_reaction, scaling_factor)) = node_stack.pop() {
         for input in current_reaction.inputs.iter() {
-            let sub_node_id = reaction_tree
-                .insert(
-                    Node::new(input.scale(scaling_factor)),
-                    InsertBehavior::UnderNode(&current_node_id))
-                .expect("Couldn't insert ore!");
+            let sub_node_id = reaction_tree.len();
+            reaction_tree.push((input.scale(scaling_factor), Some(current_node_id)));
 
             if input.el != "ORE" {
                 let sub_reaction = reactions
@@ -154,19 +149,17 @@ fn build_reaction_tree(reactions: &HashSet<Reaction>) -> Tree<Chemical> {
         };
     };
 
-    let mut s = String::new();
-    let _ = reaction_tree.write_formatted(&mut s).expect("Couldn't format tree!");
-    debug!("{}", s);
+    debug!("{:?}", reaction_tree);
 
     reaction_tree
 }
 
-fn compute_requirements(reaction_tree: &Tree<Chemical>) -> HashMap<Chemical, usize> {
+fn compute_requirements(reaction_tree: &Tree) -> HashMap<Chemical, usize> {
 	let mut requirements: HashMap<Chemical, usize> = HashMap::new();
 
-	for node in reaction_tree.traverse_pre_order(&reaction_tree.root_node_id().expect("No reaction tree root!")).expect("Can't create traverser!") {
-        if node.data().el == "ORE" {
-            let requirement = reaction_tree.get(node.parent().expect("Orphan ore!")).expect("Couldn't get ore parent!").data();
+	for (chemical, parent) in reaction_tree {
+        if chemical.el == "ORE" {
+            let (requirement, _) = &reaction_tree[parent.expect("Orphan ore!")];
             *requirements.entry(requirement.clone()).or_insert(0) += requirement.qty;
         };
     };
@@ -190,27 +183,35 @@ fn count_ore(reactions: &HashSet<Reaction>, requirements: &HashMap<Chemical, usi
 }
 
 
-fn part1(reactions: &HashSet<Reaction>) -> () {
+fn part1(reactions: &HashSet<Reaction>) -> usize {
     let reaction_tree = build_reaction_tree(reactions);
     let requirements = compute_requirements(&reaction_tree);
-    println!("Part 1: {}", count_ore(&reactions, &requirements));
+    count_ore(reactions, &requirements)
 }
 
 
-fn main() -> Result<(), std::io::Error> {
-    env_logger::init();
+pub struct Day14;
 
-    let args: Vec<String> = env::args().collect();
-    let contents: String = fs::read_to_string(&args[1])?;
+impl Solution for Day14 {
+    type Input = HashSet<Reaction>;
 
-    let mut reactions: HashSet<Reaction> = HashSet::new();
-    for line in contents.split("\n") {
-        if line != "" {
-            reactions.insert(Reaction::from_str(line));
+    fn parse(contents: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let mut reactions: HashSet<Reaction> = HashSet::new();
+        for line in contents.split("\n") {
+            if !line.is_empty() {
+                reactions.insert(Reaction::from_str(line));
+            };
         };
-    };
 
-    part1(&reactions);
+        Ok(reactions)
+    }
+
+    // ore needed for one fuel
+    fn part1(reactions: &Self::Input) -> Answer {
+        part1(reactions).into()
+    }
 
-    Ok(())
+    fn part2(_reactions: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
 }
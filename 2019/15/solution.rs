@@ -0,0 +1,318 @@
+use std::error::Error;
+use std::fs;
+use std::io;
+
+use std::collections::HashMap;
+
+use intcode::{run, Framing, Listing, Node, State, Tape};
+
+use log::{debug, log_enabled};
+use log::Level::Debug;
+use screen::Screen as Terminal;
+use std::{thread, time};
+
+use crate::{Answer, Solution};
+
+
+type Tank = HashMap<(i64, i64), i64>;
+
+fn render(tank: &Tank, pos: (i64, i64)) -> String {
+    let ((min_x, min_y), (max_x, max_y)) = screen::bounds(tank.keys()).unwrap_or(((0, 0), (0, 0)));
+
+    let mut grid = String::new();
+    for y in min_y..=max_y {
+        for x in min_x..=max_x {
+            if (x, y) == pos {
+                grid.push('D');
+            } else {
+                match tank.get(&(x, y)) {
+                    None => grid.push(' '),
+                    Some(0) => grid.push('#'),
+                    Some(1) => grid.push('.'),
+                    Some(2) => grid.push('O'),
+                    Some(tile) => panic!("Unknown tile type {}", tile)
+                };
+            };
+        };
+        grid.push('\n');
+    };
+
+    grid
+}
+
+fn visualize(terminal: &mut Terminal, tank: &Tank, pos: (i64, i64)) {
+    terminal
+        .draw(&render(tank, pos), &format!("Droid at {:?}, {} cells mapped", pos, tank.len()))
+        .expect("Couldn't draw the tank!");
+}
+
+// the droid always starts at the origin, so it's drawn there to anchor the map
+fn save_tank(tank: &Tank, path: &str) -> Result<(), std::io::Error> {
+    fs::write(path, render(tank, (0, 0)))
+}
+
+fn load_tank(contents: &str) -> Result<Tank, String> {
+    let mut cells = Vec::new();
+    let mut origin = None;
+    for (y, line) in contents.lines().enumerate() {
+        for (x, glyph) in line.chars().enumerate() {
+            let (x, y) = (x as i64, y as i64);
+            match glyph {
+                ' ' => (),
+                '#' => cells.push(((x, y), 0)),
+                '.' => cells.push(((x, y), 1)),
+                'O' => cells.push(((x, y), 2)),
+                'D' => {
+                    if let Some((first_x, first_y)) = origin {
+                        return Err(format!("Second droid at {},{}, after one at {},{}", x, y, first_x, first_y));
+                    };
+                    origin = Some((x, y));
+                    cells.push(((x, y), 1));
+                },
+                _ => return Err(format!("Unknown glyph {:?} at {},{}", glyph, x, y))
+            };
+        };
+    };
+
+    let (origin_x, origin_y) = origin.ok_or("No droid found in map")?;
+    Ok(cells
+        .into_iter()
+        .map(|((x, y), tile)| ((x - origin_x, y - origin_y), tile))
+        .collect())
+}
+
+// what the droid reports after each move; the tank stores these as tiles
+#[derive(Clone, Copy)]
+enum Status {
+    Wall = 0,
+    Moved = 1,
+    Oxygen = 2
+}
+
+fn statuses() -> Framing<Status> {
+    Framing::new(1, |frame| match frame[0] {
+        0 => Status::Wall,
+        1 => Status::Moved,
+        2 => Status::Oxygen,
+        res => panic!("Unknown output {}", res)
+    })
+}
+
+#[derive(Clone, Copy)]
+enum Direction {
+    North,
+    South,
+    West,
+    East
+}
+
+impl Direction {
+    fn to_command(self) -> i64 {
+        match self {
+            Direction::North => 1,
+            Direction::South => 2,
+            Direction::West => 3,
+            Direction::East => 4
+        }
+    }
+
+    fn to_diff(self) -> (i64, i64) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::South => (0, 1),
+            Direction::West => (-1, 0),
+            Direction::East => (1, 0)
+        }
+    }
+
+    fn opposite(&self) -> Self {
+        match self {
+            Direction::North => Direction::South,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+            Direction::East => Direction::West,
+        }
+    }
+}
+
+// depth-first: step into the first unexplored neighbor, if any
+fn unexplored(tank: &Tank, pos: (i64, i64)) -> Option<Direction> {
+    let (x, y) = pos;
+
+    for new_dir in [Direction::North, Direction::South, Direction::West, Direction::East] {
+        let (x_diff, y_diff) = new_dir.to_diff();
+        let (new_x, new_y) = (x + x_diff, y + y_diff);
+        if tank.get(&(new_x, new_y)).is_none() {
+            return Some(new_dir);
+        };
+    };
+
+    None
+}
+
+
+// explores depth-first, backing out along `path` once everything around is
+// known, so it stops with the whole tank mapped; the droid never halts by
+// itself. `record` is where to save a tape of the run, if anywhere
+fn execute(listing: &Listing, record: Option<&str>) -> (Tank, (i64, i64)) {
+    let mut tank: Tank = HashMap::new();
+    assert!(tank.insert((0, 0), 1).is_none(), "?!");
+
+    let mut pos_x = 0;
+    let mut pos_y = 0;
+    let mut dir = Direction::North;
+    let mut path: Vec<Direction> = Vec::new();
+    let mut backtracking = false;
+
+    let mut node = Node::new('A', &listing.program);
+    listing.header.apply(&mut node);
+    // the droid only ever waits on a move, so spinning without one is a bug
+    node.detect_loops = true;
+    if record.is_some() {
+        node.tape = Some(Tape::start(&listing.program, &node));
+    };
+    let framing = statuses();
+    let mut terminal = if crate::animate() {
+        Some(Terminal::new().expect("Couldn't set up the terminal!"))
+    } else { None };
+
+    while node.state != State::Halted {
+        if log_enabled!(Debug) {
+            println!("Press Enter to execute");
+            let _ = io::stdin().read_line(&mut String::new()).expect("Couldn't read line!");
+        };
+
+        debug!("BEGIN RUN: {:?}", node);
+        run(&mut node);
+        debug!("END RUN: {:?}", node);
+
+        for status in framing.take(&mut node).unwrap_or_else(|e| panic!("{}", e)) {
+            let (x_diff, y_diff) = dir.to_diff();
+            let (new_x, new_y) = (pos_x + x_diff, pos_y + y_diff);
+            let tile = status as i64;
+            assert!(tile == *tank.entry((new_x, new_y)).or_insert(tile));
+            match status {
+                Status::Wall => (),
+                Status::Moved | Status::Oxygen => {
+                    pos_x = new_x;
+                    pos_y = new_y;
+                    if !backtracking {
+                        path.push(dir);
+                    };
+                }
+            };
+        };
+
+        match node.state {
+            State::InputWait => {
+                match unexplored(&tank, (pos_x, pos_y)) {
+                    Some(new_dir) => {
+                        dir = new_dir;
+                        backtracking = false;
+                    },
+                    None => match path.pop() {
+                        Some(last_dir) => {
+                            dir = last_dir.opposite();
+                            backtracking = true;
+                        },
+                        // back at the start with nothing left to explore
+                        None => break
+                    }
+                };
+                node.input.push_back(dir.to_command());
+                if let Some(terminal) = &mut terminal {
+                    visualize(terminal, &tank, (pos_x, pos_y));
+                    thread::sleep(time::Duration::from_millis(15));
+                };
+            },
+            State::LoopDetected => panic!("Droid is stuck in a loop at ip {}!", node.ip),
+            State::Faulted => panic!("Droid faulted: {}", node.fault.as_ref().expect("No fault recorded!")),
+            _ => ()
+        };
+    }
+    if let Some(terminal) = &mut terminal {
+        visualize(terminal, &tank, (pos_x, pos_y));
+    };
+
+    if let Some(path) = record {
+        node.tape.take().expect("No tape recorded!").save(path).expect("Couldn't save tape!");
+    };
+
+    (tank, (pos_x, pos_y))
+}
+
+
+pub struct Droid {
+    listing: Listing,
+    // a map saved earlier, to draw instead of exploring
+    loaded: Option<Tank>,
+    // where to save the map, if anywhere
+    save_path: Option<String>,
+    // where to save a tape of the run, if anywhere
+    record: Option<String>
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Droid;
+
+    fn parse(contents: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(Droid { listing: intcode::parse(contents)?, loaded: None, save_path: None, record: None })
+    }
+
+    // usage: aoc run 2019 15 [-- [--save <map>] [--record <tape>]]
+    //        aoc run 2019 15 -- --load <map>
+    fn configure(droid: &mut Self::Input, args: Vec<String>) -> Result<(), Box<dyn Error>> {
+        let (mut args, setup) = intcode::options(args)?;
+        droid.listing.header.merge(setup);
+        droid.record = intcode::take_option(&mut args, "--record")?;
+        droid.save_path = intcode::take_option(&mut args, "--save")?;
+        if let Some(path) = intcode::take_option(&mut args, "--load")? {
+            droid.loaded = Some(load_tank(&fs::read_to_string(path)?)?);
+        };
+        crate::reject_options(&args)
+    }
+
+    // maps the tank, but doesn't find the way to the oxygen system yet
+    fn part1(droid: &Self::Input) -> Answer {
+        if let Some(tank) = &droid.loaded {
+            if crate::animate() {
+                visualize(&mut Terminal::new().expect("Couldn't set up the terminal!"), tank, (0, 0));
+            } else {
+                crate::detail(&render(tank, (0, 0)));
+            };
+            return Answer::Unsolved;
+        };
+
+        let (tank, _) = execute(&droid.listing, droid.record.as_deref());
+        if let Some(path) = &droid.save_path {
+            save_tank(&tank, path).expect("Couldn't save map!");
+        };
+        Answer::Unsolved
+    }
+
+    fn part2(_droid: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+#[test]
+fn saving_and_loading() {
+    let mut tank: Tank = HashMap::new();
+    for (x, y, tile) in [(-1, -1, 0), (0, -1, 0), (1, -1, 0), (-1, 0, 0), (0, 0, 1), (1, 0, 2), (0, 1, 1), (1, 1, 0)] {
+        tank.insert((x, y), tile);
+    };
+
+    let path = std::env::temp_dir().join("aoc-2019-15-tank.txt");
+    save_tank(&tank, path.to_str().unwrap()).unwrap();
+    assert_eq!(fs::read_to_string(&path).unwrap(), "###\n#DO\n .#\n");
+    assert_eq!(load_tank(&fs::read_to_string(&path).unwrap()), Ok(tank));
+}
+
+#[test]
+fn loading_bad_maps() {
+    assert!(load_tank("#D?\n").is_err());
+    assert!(load_tank("#..\n").is_err());
+    assert!(load_tank("#D.\n.D#\n").is_err());
+}
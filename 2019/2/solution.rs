@@ -0,0 +1,108 @@
+use std::error::Error;
+
+use std::convert::TryFrom;
+use std::iter::successors;
+
+use intcode::Header;
+
+use crate::{Answer, Solution};
+
+type Program = Vec<usize>;
+
+fn step(program: Program, index: usize) -> Option<Program> {
+    let mut new_program = program.to_vec();
+    match program[index] {
+        99 => None,
+        1 => {
+            let val1 = program[program[index + 1]];
+            let val2 = program[program[index + 2]];
+            let store_idx = program[index + 3];
+            new_program[store_idx] = val1 + val2;
+            Some(new_program)
+        },
+        2 => {
+            let val1 = program[program[index + 1]];
+            let val2 = program[program[index + 2]];
+            let store_idx = program[index + 3];
+            new_program[store_idx] = val1 * val2;
+            Some(new_program)
+        },
+        opcode => panic!("Unknown opcode {}", opcode)
+    }
+}
+
+fn eval(initial: Program) -> Vec<Program> {
+    let mut index = 0;
+    successors(Some(initial), |prev| {
+        let old_index = index;
+        index += 4;
+        step(prev.to_vec(), old_index)
+    }).collect()
+}
+
+// patches from the command line go in after the noun and verb, so they can
+// override them
+fn initialize(program: &Program, noun: usize, verb: usize, setup: &Header) -> Program {
+    let mut new_program = program.to_vec();
+    new_program[1] = noun;
+    new_program[2] = verb;
+    for &(addr, value) in &setup.patches {
+        if addr >= new_program.len() {
+            new_program.resize(addr + 1, 0);
+        };
+        new_program[addr] = usize::try_from(value).expect("Cells can't be negative!");
+    };
+
+    new_program
+}
+
+fn get_result(steps: Vec<Program>) -> usize {
+    steps.last().unwrap()[0]
+}
+
+// the program, and patches from the command line
+pub struct Gravity {
+    program: Program,
+    setup: Header
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Gravity;
+
+    fn parse(contents: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let program = contents.trim().split(",").map(|s| s.parse::<usize>()).collect::<Result<_, _>>()?;
+        Ok(Gravity { program, setup: Header::default() })
+    }
+
+    fn configure(input: &mut Self::Input, args: Vec<String>) -> Result<(), Box<dyn Error>> {
+        let (args, setup) = intcode::options(args)?;
+        crate::reject_options(&args)?;
+        if !setup.inputs.is_empty() {
+            eprintln!("Ignoring inputs, since nothing here reads them");
+        };
+        input.setup.merge(setup);
+        Ok(())
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let initial = initialize(&input.program, 12, 2, &input.setup);
+        let steps = eval(initial);
+        get_result(steps).into()
+    }
+
+    // 100 * noun + verb, for the noun and verb that give 19690720
+    fn part2(input: &Self::Input) -> Answer {
+        for noun in 0..100 {
+            for verb in 0..100 {
+                let initial = initialize(&input.program, noun, verb, &input.setup);
+                let steps = eval(initial);
+                if get_result(steps) == 19690720 {
+                    return (100 * noun + verb).into();
+                };
+            };
+        };
+        Answer::Unsolved
+    }
+}
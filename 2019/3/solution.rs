@@ -1,14 +1,15 @@
-use std::env;
-use std::fs;
+use std::error::Error;
 
 use std::fmt;
 use std::cmp;
 use std::hash;
 use std::collections::HashMap;
 
+use crate::{Answer, Solution};
+
 
 #[derive(cmp::PartialEq, cmp::Eq, hash::Hash)]
-struct Coord {
+pub struct Coord {
     x: i64,
     y: i64
 }
@@ -68,7 +69,7 @@ impl fmt::Debug for Instruction {
 type Wire = u64;
 type Grid = HashMap<Coord, HashMap<Wire, u64>>;
 
-fn travel(grid: &mut Grid, wire: Wire, path: &Path) -> () {
+fn travel(grid: &mut Grid, wire: Wire, path: &Path) {
     let mut x = 0;
     let mut y = 0;
     let mut step_count = 0;
@@ -102,7 +103,14 @@ fn travel(grid: &mut Grid, wire: Wire, path: &Path) -> () {
 }
 
 
-fn part1(intersections: &Vec<(&Coord, &HashMap<Wire, u64>)>) -> () {
+fn intersections(grid: &Grid) -> Vec<(&Coord, &HashMap<Wire, u64>)> {
+    grid
+        .iter()
+        .filter(|(_, wires)| wires.len() >= 2)
+        .collect()
+}
+
+fn part1(intersections: &Vec<(&Coord, &HashMap<Wire, u64>)>) -> u64 {
     let origin = Coord { x: 0, y: 0 };
     let (closest_to_origin, _) = intersections
         .iter()
@@ -111,55 +119,56 @@ fn part1(intersections: &Vec<(&Coord, &HashMap<Wire, u64>)>) -> () {
                 .cmp(&manhattan_distance(i2, &origin)))
         .unwrap();
 
-    println!("Part 1: {}", manhattan_distance(closest_to_origin, &origin));
+    manhattan_distance(closest_to_origin, &origin)
 }
 
-fn part2(intersections: &Vec<(&Coord, &HashMap<Wire, u64>)>) -> () {
-    let minimum_delay = intersections
+fn part2(intersections: &Vec<(&Coord, &HashMap<Wire, u64>)>) -> u64 {
+    intersections
         .iter()
         .min_by(|(_, ws1), (_, ws2)|
                 ws1.values().sum::<u64>().cmp(&ws2.values().sum::<u64>()))
         .unwrap()
         .1
         .values()
-        .sum::<u64>();
-
-    println!("Part 2: {}", minimum_delay);
+        .sum::<u64>()
 }
 
 
-fn main() -> Result<(), std::io::Error> {
-    let args: Vec<String> = env::args().collect();
-    let contents: String = fs::read_to_string(&args[1])?;
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Grid;
 
-    let mut lines: Vec<String> = Vec::new();
-    for line in contents.split("\n") {
-        if line != "" {
-            lines.push(line.to_string());
+    fn parse(contents: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let mut lines: Vec<String> = Vec::new();
+        for line in contents.split("\n") {
+            if !line.is_empty() {
+                lines.push(line.to_string());
+            };
         };
-    };
 
-    let paths: Vec<Path> = lines
-        .iter()
-        .map(|line| line
-            .split(",")
-            .map(Instruction::from_str)
-            .collect())
-        .collect();
+        let paths: Vec<Path> = lines
+            .iter()
+            .map(|line| line
+                .split(",")
+                .map(Instruction::from_str)
+                .collect())
+            .collect();
 
-    let mut grid: Grid = HashMap::new();
+        let mut grid: Grid = HashMap::new();
 
-    for (wire, path) in (1..).zip(paths) {
-        travel(&mut grid, wire, &path);
-    };
+        for (wire, path) in (1..).zip(paths) {
+            travel(&mut grid, wire, &path);
+        };
 
-    let intersections: Vec<(&Coord, &HashMap<Wire, u64>)> = grid
-        .iter()
-        .filter(|(_, wires)| wires.len() >= 2)
-        .collect();
+        Ok(grid)
+    }
 
-    part1(&intersections);
-    part2(&intersections);
+    fn part1(grid: &Self::Input) -> Answer {
+        part1(&intersections(grid)).into()
+    }
 
-    Ok(())
+    fn part2(grid: &Self::Input) -> Answer {
+        part2(&intersections(grid)).into()
+    }
 }
@@ -1,4 +1,6 @@
-use std::env;
+use std::error::Error;
+
+use crate::{Answer, Solution};
 
 trait DigitsWrapper {
     fn digits(self) -> Vec<u64>;
@@ -44,7 +46,7 @@ fn get_runs(pw: &u64) -> Vec<Vec<u64>> {
 }
 
 fn contains_adjacent(pw: &u64) -> bool {
-    get_runs(pw).len() > 0
+    !get_runs(pw).is_empty()
 }
 
 fn is_nondecreasing(pw: &u64) -> bool {
@@ -73,19 +75,28 @@ fn part2(lower_bound: u64, upper_bound: u64) -> usize {
         .count()
 }
 
-fn main() -> Result<(), std::io::Error> {
-    let args: Vec<String> = env::args().collect();
-    let contents: String = args[1].to_string();
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = (u64, u64);
 
-    let range = contents
-        .split("-")
-        .map(|i| i.parse::<u64>().unwrap())
-        .collect::<Vec<u64>>();
-    let lower_bound = range[0];
-    let upper_bound = range[1];
+    fn parse(contents: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let range = contents
+            .trim()
+            .split("-")
+            .map(|i| i.parse::<u64>())
+            .collect::<Result<Vec<u64>, _>>()?;
+        match range[..] {
+            [lower_bound, upper_bound] => Ok((lower_bound, upper_bound)),
+            _ => Err(format!("Expected a range like 123-456, got {:?}", contents.trim()).into())
+        }
+    }
 
-    println!("Part 1: {}", part1(lower_bound, upper_bound));
-    println!("Part 2: {}", part2(lower_bound, upper_bound));
+    fn part1(&(lower_bound, upper_bound): &Self::Input) -> Answer {
+        part1(lower_bound, upper_bound).into()
+    }
 
-    Ok(())
+    fn part2(&(lower_bound, upper_bound): &Self::Input) -> Answer {
+        part2(lower_bound, upper_bound).into()
+    }
 }
@@ -0,0 +1,65 @@
+use std::error::Error;
+
+use intcode::{run, Listing, Node, State};
+
+use crate::{Answer, Solution};
+
+
+// inputs come from the program's header or the command line, and there's no
+// asking for more once they run out
+fn eval(mut node: Node) -> i64 {
+    let mut outputs = Vec::new();
+
+    while node.state != State::Halted {
+        run(&mut node);
+        match node.state {
+            State::InputWait => panic!("Ran out of inputs at ip {}, give more with --input", node.ip),
+            State::OutputYield => outputs.push(node.output.pop_front().unwrap()),
+            State::Faulted => panic!("{}", node.fault.as_ref().expect("No fault recorded!")),
+            _ => ()
+        };
+    };
+
+    let output = outputs.pop().unwrap();
+    if outputs.iter().any(|o| *o != 0) {
+        panic!("Nonzero diagnostics received!\nOuptuts: {:?}\nLast output: {:?}",
+               outputs, output);
+    };
+    output
+}
+
+
+// the air conditioner's id is 1, and the thermal radiator controller's is 5
+fn diagnose(listing: &Listing, system: i64) -> i64 {
+    let mut node = listing.boot('A');
+    if node.input.is_empty() {
+        node.input.push_back(system);
+    };
+    eval(node)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Listing;
+
+    fn parse(contents: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(intcode::parse(contents)?)
+    }
+
+    // usage: aoc run 2019 5 [-- --input <system id>,...]
+    fn configure(listing: &mut Self::Input, args: Vec<String>) -> Result<(), Box<dyn Error>> {
+        let (args, setup) = intcode::options(args)?;
+        crate::reject_options(&args)?;
+        listing.header.merge(setup);
+        Ok(())
+    }
+
+    fn part1(listing: &Self::Input) -> Answer {
+        diagnose(listing, 1).into()
+    }
+
+    fn part2(listing: &Self::Input) -> Answer {
+        diagnose(listing, 5).into()
+    }
+}
@@ -0,0 +1,76 @@
+use std::error::Error;
+
+use std::collections::HashMap;
+
+use crate::{Answer, Solution};
+
+
+type Galaxy<'a> = HashMap<&'a str, &'a str>;
+
+fn path_to<'a>(galaxy: &'a Galaxy, planet: &'a str, target: &'a str) -> Vec<&'a str> {
+    if planet == target {
+        vec!()
+    } else if planet == "COM" {
+        panic!("Does not orbit {}", target)
+    } else {
+        let mut path = path_to(galaxy, galaxy.get(planet).unwrap(), target);
+        path.insert(0, planet);
+        path
+    }
+}
+
+fn part1(galaxy: &Galaxy) -> usize {
+    galaxy.keys().fold(0, |sum, planet| sum + path_to(galaxy, planet, "COM").len())
+}
+
+fn part2(galaxy: &Galaxy) -> usize {
+    let you_path = path_to(galaxy, "YOU", "COM");
+    let san_path = path_to(galaxy, "SAN", "COM");
+
+    let tri_point = you_path
+        .iter()
+        .find_map(|p1| san_path.iter().find(|&p2| p1 == p2))
+        .unwrap();
+
+    path_to(galaxy, "YOU", tri_point).len()
+        + path_to(galaxy, "SAN", tri_point).len()
+        - 2
+}
+
+// the input's lines, moon to planet; a Galaxy borrows from these
+pub type Orbits = HashMap<String, String>;
+
+fn galaxy(orbits: &Orbits) -> Galaxy<'_> {
+    orbits
+        .iter()
+        .map(|(moon, planet)| (moon.as_str(), planet.as_str()))
+        .collect()
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Orbits;
+
+    fn parse(contents: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let mut orbits: Orbits = HashMap::new();
+        for line in contents.split("\n") {
+            if !line.is_empty() {
+                let orbit = line.split(")").collect::<Vec<_>>();
+                let moon = orbit[1];
+                let planet = orbit[0];
+                orbits.insert(moon.to_string(), planet.to_string());
+            };
+        };
+
+        Ok(orbits)
+    }
+
+    fn part1(orbits: &Self::Input) -> Answer {
+        part1(&galaxy(orbits)).into()
+    }
+
+    fn part2(orbits: &Self::Input) -> Answer {
+        part2(&galaxy(orbits)).into()
+    }
+}
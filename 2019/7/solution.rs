@@ -0,0 +1,198 @@
+use std::error::Error;
+
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::thread;
+
+use intcode::{run, Header, Listing, Node, Program, State};
+
+use itertools::Itertools;
+use log::debug;
+
+use crate::{Answer, Solution};
+
+
+// runs the amplifiers with some set of phases, returning the final thrust
+type Scheduler = fn(&Program, &Header, Vec<&i64>) -> i64;
+
+fn run_with_phases(program: &Program, setup: &Header, phases: Vec<&i64>) -> i64 {
+    let labels = ['A', 'B', 'C', 'D', 'E'];
+    let mut nodes: Vec<Node> = labels
+        .iter()
+        .zip(phases.iter())
+        .map(|(&label, &&phase)| {
+            let mut node = Node::new(label, program);
+            for &(addr, value) in &setup.patches {
+                node.set(addr, value);
+            };
+            node.input.push_back(phase);
+            node
+        })
+        .collect();
+
+    nodes[0].input.push_back(0);
+
+    // nodes yield after every output, so E's last signal gets forwarded to A
+    // before E halts; hold on to it as it goes by
+    let mut thrust = None;
+    let (mut curr_node, mut next_node) = (0, 1);
+    loop {
+        debug!("BEGIN RUN: {:?}", nodes[curr_node]);
+        run(&mut nodes[curr_node]);
+        debug!("END RUN: {:?}", nodes[curr_node]);
+        if let Some(fault) = &nodes[curr_node].fault {
+            panic!("Node {}: {}", nodes[curr_node].label, fault);
+        };
+        if nodes.iter().all(|node| node.state == State::Halted) {
+            break;
+        };
+        if let Some(output) = nodes[curr_node].output.pop_front() {
+            if curr_node == nodes.len() - 1 {
+                thrust = Some(output);
+            };
+            nodes[next_node].input.push_back(output);
+        };
+        curr_node += 1;
+        curr_node %= nodes.len();
+        next_node += 1;
+        next_node %= nodes.len();
+    }
+
+    thrust.expect("No outputs!")
+}
+
+// one amplifier on its own thread, blocking on its input channel and sending
+// each output straight to the next. it stops when it halts, or when the
+// amplifier feeding it has halted and gone quiet; dropping its sender then
+// tells the next one the same. returns its last output
+fn amplify(mut node: Node, input: Receiver<i64>, output: Sender<i64>) -> Option<i64> {
+    let mut last = None;
+    loop {
+        debug!("BEGIN RUN: {:?}", node);
+        run(&mut node);
+        debug!("END RUN: {:?}", node);
+        match node.state {
+            State::InputWait => match input.recv() {
+                Ok(value) => node.input.push_back(value),
+                Err(_) => break
+            },
+            State::OutputYield => {
+                let value = node.output.pop_front().expect("No output after yield!");
+                last = Some(value);
+                // whoever's downstream may have halted already, in which case
+                // nobody needs this
+                let _ = output.send(value);
+            },
+            State::Halted => break,
+            state => panic!("Node {} stopped in state {:?}", node.label, state)
+        };
+    };
+
+    last
+}
+
+fn run_threaded(program: &Program, setup: &Header, phases: Vec<&i64>) -> i64 {
+    let labels = ['A', 'B', 'C', 'D', 'E'];
+    let (senders, receivers): (Vec<Sender<i64>>, Vec<Receiver<i64>>) = labels
+        .iter()
+        .map(|_| channel())
+        .unzip();
+
+    for (sender, &&phase) in senders.iter().zip(phases.iter()) {
+        sender.send(phase).expect("Couldn't send phase!");
+    };
+    senders[0].send(0).expect("Couldn't send first signal!");
+
+    // each amplifier's output goes to the next one's input, and E's to A's
+    let mut threads = Vec::new();
+    for (i, (&label, input)) in labels.iter().zip(receivers).enumerate() {
+        let mut node = Node::new(label, program);
+        for &(addr, value) in &setup.patches {
+            node.set(addr, value);
+        };
+        let output = senders[(i + 1) % labels.len()].clone();
+        threads.push(thread::spawn(move || amplify(node, input, output)));
+    };
+    // only the amplifiers hold senders now, so channels close as they halt
+    drop(senders);
+
+    threads
+        .into_iter()
+        .map(|thread| thread.join().expect("Amplifier panicked!"))
+        .last()
+        .expect("No amplifiers!")
+        .expect("No outputs!")
+}
+
+// patches go to every amplifier. given inputs are taken as the one phase
+// sequence to try, instead of searching all of them
+fn find_max_output(program: &Program, setup: &Header, scheduler: Scheduler, possible_phases: Vec<i64>) -> i64 {
+    if !setup.inputs.is_empty() {
+        return scheduler(program, setup, setup.inputs.iter().collect());
+    };
+
+    possible_phases
+        .iter()
+        .permutations(possible_phases.len())
+        .map(|phases| scheduler(program, setup, phases.to_vec()))
+        .max()
+        .expect("No maximum output!")
+}
+
+pub struct Amplifiers {
+    listing: Listing,
+    scheduler: Scheduler
+}
+
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Amplifiers;
+
+    fn parse(contents: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(Amplifiers { listing: intcode::parse(contents)?, scheduler: run_with_phases })
+    }
+
+    // usage: aoc run 2019 7 [-- --threads]
+    fn configure(amplifiers: &mut Self::Input, args: Vec<String>) -> Result<(), Box<dyn Error>> {
+        let (mut args, setup) = intcode::options(args)?;
+        amplifiers.listing.header.merge(setup);
+        if let Some(i) = args.iter().position(|arg| arg == "--threads") {
+            args.remove(i);
+            amplifiers.scheduler = run_threaded;
+        };
+        crate::reject_options(&args)
+    }
+
+    fn part1(amplifiers: &Self::Input) -> Answer {
+        let Amplifiers { listing, scheduler } = amplifiers;
+        find_max_output(&listing.program, &listing.header, *scheduler, vec!(0, 1, 2, 3, 4)).into()
+    }
+
+    fn part2(amplifiers: &Self::Input) -> Answer {
+        let Amplifiers { listing, scheduler } = amplifiers;
+        find_max_output(&listing.program, &listing.header, *scheduler, vec!(5, 6, 7, 8, 9)).into()
+    }
+}
+
+#[test]
+fn threads_agree_with_taking_turns() {
+    let samples = [include_str!("sample4.txt"), include_str!("sample5.txt"), include_str!("input.txt")];
+    for contents in samples.iter() {
+        let Listing { program, header } = intcode::parse(contents).unwrap();
+        for phases in (5..=9).permutations(5).take(20) {
+            let phases = phases.iter().collect::<Vec<_>>();
+            assert_eq!(run_threaded(&program, &header, phases.clone()), run_with_phases(&program, &header, phases));
+        };
+        assert_eq!(
+            find_max_output(&program, &header, run_threaded, vec!(5, 6, 7, 8, 9)),
+            find_max_output(&program, &header, run_with_phases, vec!(5, 6, 7, 8, 9))
+        );
+    };
+
+    let Listing { program, header } = intcode::parse(include_str!("input.txt")).unwrap();
+    assert_eq!(
+        find_max_output(&program, &header, run_threaded, vec!(0, 1, 2, 3, 4)),
+        find_max_output(&program, &header, run_with_phases, vec!(0, 1, 2, 3, 4))
+    );
+}
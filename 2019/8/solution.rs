@@ -0,0 +1,144 @@
+use std::error::Error;
+
+use crate::{Answer, Solution};
+
+type Pixel = u64;
+type Row = Vec<Pixel>;
+type Layer = Vec<Row>;
+type Image = Vec<Layer>;
+
+fn process_image(raw: &str, width: usize, height: usize) -> Image {
+    let len = raw.len();
+    let mut pixels = raw
+        .chars()
+        .map(|digit| digit.to_digit(10).expect("Couldn't parse pixel!") as Pixel);
+
+    let mut image = Vec::new();
+    let num_layers = len / (width * height);
+    for _ in 0..num_layers {
+        let mut layer = Vec::new();
+        for _ in 0..height {
+            let mut row = Vec::new();
+            for _ in 0..width {
+                row.push(pixels.next().expect("Unexpected end-of-image"));
+            };
+            layer.push(row);
+        };
+        image.push(layer);
+    };
+
+    image
+}
+
+fn flatten(image: &Image) -> Layer {
+    let mut flat: Layer = image[0].to_vec();
+
+    for layer in image.iter().skip(1) {
+        for (r, row) in layer.iter().enumerate() {
+            for (c, &pixel) in row.iter().enumerate() {
+                if flat[r][c] == 2 {
+                    flat[r][c] = pixel;
+                };
+            };
+        };
+    };
+
+    flat
+}
+
+fn display(image: &Image) {
+    let flat = flatten(image);
+
+    let mut picture = String::new();
+    for row in flat {
+        for pixel in row {
+            picture.push_str(match pixel {
+                0 => "⬛",
+                1 => "⬜",
+                2 => "  ",
+                _ => panic!("Unknown pixel {}", pixel)
+            });
+        };
+        picture.push('\n');
+    };
+    crate::detail(&picture);
+}
+
+
+fn part1(image: &Image) -> usize {
+    let min_layer: &Layer = image
+        .iter()
+        .min_by(|layer1, layer2| {
+            let count = |layer: &Layer| layer
+                .iter()
+                .flatten()
+                .filter(|&&pixel| pixel == 0)
+                .count();
+            count(layer1).cmp(&count(layer2))
+        })
+        .expect("No layer with fewest 0 pixels!");
+
+    let num_1_pixels = min_layer
+        .iter()
+        .flatten()
+        .filter(|&&pixel| pixel == 1)
+        .count();
+    let num_2_pixels = min_layer
+        .iter()
+        .flatten()
+        .filter(|&&pixel| pixel == 2)
+        .count();
+
+    num_1_pixels * num_2_pixels
+}
+
+// the message in the image. if it can't be read, the image gets drawn so
+// someone can have a look, and the part's left unsolved
+fn part2(image: &Image) -> Answer {
+    let grid = flatten(image)
+        .iter()
+        .map(|row| row.iter().map(|&pixel| pixel == 1).collect())
+        .collect::<Vec<Vec<bool>>>();
+
+    match ocr::recognize(&grid) {
+        Ok(message) => message.into(),
+        Err(e) => {
+            crate::detail(&format!("Couldn't read the image: {}\n", e));
+            display(image);
+            Answer::Unsolved
+        }
+    }
+}
+
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Image;
+
+    fn parse(contents: &str) -> Result<Self::Input, Box<dyn Error>> {
+        let mut lines: Vec<String> = Vec::new();
+        for line in contents.split("\n") {
+            if !line.is_empty() {
+                lines.push(line.to_string());
+            };
+        };
+
+        let mut dimensions = lines[0].split('x');
+        let (width, height) = (
+            dimensions.next().expect("No width!").parse().expect("Couldn't parse width!"),
+            dimensions.next().expect("No height!").parse().expect("Couldn't parse height!")
+        );
+        assert!(dimensions.next().is_none(), "Extraneous dimensions!");
+        let raw = &lines[1];
+        Ok(process_image(raw, width, height))
+    }
+
+    fn part1(image: &Self::Input) -> Answer {
+        part1(image).into()
+    }
+
+    fn part2(image: &Self::Input) -> Answer {
+        part2(image)
+    }
+}
@@ -0,0 +1,90 @@
+use std::error::Error;
+
+use intcode::{run, Arithmetic, Header, Listing, Node, Program, State};
+
+use log::debug;
+
+use crate::{Answer, Solution};
+
+
+// every node starts with the same setup
+fn execute(program: &Program, node_labels: Vec<char>, setup: &Header) -> Vec<i64> {
+    let mut nodes: Vec<Node> = node_labels
+        .iter()
+        .map(|&label| {
+            let mut node = Node::new(label, program);
+            node.arithmetic = Arithmetic::Checked;
+            setup.apply(&mut node);
+            node
+        })
+        .collect();
+
+    let (mut curr_node, mut next_node) = (0, 1 % nodes.len());
+    loop {
+        debug!("BEGIN RUN: {:?}", nodes[curr_node]);
+        run(&mut nodes[curr_node]);
+        debug!("END RUN: {:?}", nodes[curr_node]);
+        if let Some(fault) = &nodes[curr_node].fault {
+            panic!("Node {}: {}", nodes[curr_node].label, fault);
+        };
+        if nodes.iter().all(|node| node.state == State::Halted) {
+            break;
+        };
+        // a lone node has nowhere to send its output, so it stays put
+        if next_node != curr_node {
+            if let Some(output) = nodes[curr_node].output.pop_front() {
+                nodes[next_node].input.push_back(output);
+            };
+        };
+        curr_node += 1;
+        curr_node %= nodes.len();
+        next_node += 1;
+        next_node %= nodes.len();
+    }
+
+    Vec::from(nodes.last().expect("No last node!").output.clone())
+}
+
+
+fn boost(program: &Program, mode: i64, setup: &Header) -> Vec<i64> {
+    let mut header = Header { inputs: vec!(mode), ..Header::default() };
+    header.merge(setup.clone());
+    execute(program, vec!('A'), &header)
+}
+
+// a working machine outputs just the keycode, otherwise it lists the opcodes
+// that seem broken first, which is no answer
+fn boost_keycode(listing: &Listing, mode: i64) -> Answer {
+    match boost(&listing.program, mode, &listing.header)[..] {
+        [keycode] => keycode.into(),
+        ref outputs => {
+            crate::detail(&format!("BOOST reported broken opcodes: {:?}\n", outputs));
+            Answer::Unsolved
+        }
+    }
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Listing;
+
+    fn parse(contents: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(intcode::parse(contents)?)
+    }
+
+    fn configure(listing: &mut Self::Input, args: Vec<String>) -> Result<(), Box<dyn Error>> {
+        let (args, setup) = intcode::options(args)?;
+        crate::reject_options(&args)?;
+        listing.header.merge(setup);
+        Ok(())
+    }
+
+    fn part1(listing: &Self::Input) -> Answer {
+        boost_keycode(listing, 1)
+    }
+
+    fn part2(listing: &Self::Input) -> Answer {
+        boost_keycode(listing, 2)
+    }
+}
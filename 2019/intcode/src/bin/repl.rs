@@ -0,0 +1,33 @@
+use std::env;
+use std::io::{self, BufRead, Write};
+
+use intcode::repl::Shell;
+
+
+fn main() -> Result<(), io::Error> {
+    env_logger::init();
+
+    let (args, setup) = intcode::options(env::args().collect())?;
+    if args.len() != 2 {
+        eprintln!("usage: intcode <program> [--patch ...] [--input ...] [--setup <file>]");
+        std::process::exit(2);
+    };
+    let mut listing = intcode::load(&args[1])?;
+    listing.header.merge(setup);
+    let mut shell = Shell::new(listing);
+    println!("{:?}", shell.node());
+
+    let stdin = io::stdin();
+    let mut stdout = io::stdout();
+    loop {
+        print!("> ");
+        stdout.flush()?;
+
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line)? == 0 || !shell.handle(&line, &mut stdout)? {
+            break;
+        };
+    };
+
+    Ok(())
+}
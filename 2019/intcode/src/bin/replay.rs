@@ -0,0 +1,28 @@
+use std::env;
+use std::process;
+
+use intcode::replay;
+
+
+fn main() -> Result<(), std::io::Error> {
+    env_logger::init();
+
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 3 {
+        eprintln!("usage: intcode-replay <program> <tape>");
+        process::exit(2);
+    };
+    let listing = intcode::load(&args[1])?;
+    let tape = intcode::load_tape(&args[2])?;
+
+    match replay(&listing.program, &tape) {
+        Ok(replayed) => println!("Tape matches: {} entries, {} inputs",
+                                 replayed.entries.len(), replayed.inputs().count()),
+        Err(divergence) => {
+            eprintln!("{}", divergence);
+            process::exit(1);
+        }
+    };
+
+    Ok(())
+}
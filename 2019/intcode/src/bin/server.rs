@@ -0,0 +1,93 @@
+use std::env;
+use std::fs;
+use std::io::{self, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::os::unix::fs::FileTypeExt;
+use std::os::unix::net::UnixListener;
+use std::path::Path;
+use std::process;
+use std::sync::Arc;
+use std::thread;
+
+use intcode::{serve, Listing};
+
+
+fn usage() -> ! {
+    eprintln!("usage: intcode-server <program> (--tcp <port> | --unix <path>) [--budget <n>] [--patch ...] [--input ...] [--setup <file>]");
+    process::exit(2);
+}
+
+// one session, with its own node, per connection
+fn spawn_session<R, W>(listing: Arc<Listing>, budget: Option<usize>, reader: R, writer: W)
+    where R: Read + Send + 'static, W: Write + Send + 'static
+{
+    thread::spawn(move || {
+        if let Err(e) = serve::serve(&listing, budget, BufReader::new(reader), writer) {
+            eprintln!("Session ended: {}", e);
+        };
+    });
+}
+
+// a socket left behind by a server that's gone would stop the next one
+// binding, but anything that isn't a socket is left for `bind` to complain
+// about
+fn remove_socket(path: &Path) {
+    if fs::metadata(path).map(|meta| meta.file_type().is_socket()).unwrap_or(false) {
+        let _ = fs::remove_file(path);
+    };
+}
+
+fn main() -> io::Result<()> {
+    env_logger::init();
+
+    let (mut args, setup) = intcode::options(env::args().skip(1).collect())?;
+    let mut take_option = |flag| intcode::take_option(&mut args, flag).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        usage()
+    });
+    let budget = take_option("--budget").map(|budget| budget.parse().expect("Couldn't parse budget!"));
+    let tcp = take_option("--tcp");
+    let unix = take_option("--unix");
+    let path = match args.as_slice() {
+        [path] => path,
+        _ => usage()
+    };
+    let mut listing = intcode::load(path)?;
+    listing.header.merge(setup);
+    let listing = Arc::new(listing);
+
+    match (tcp, unix) {
+        (Some(port), None) => {
+            let listener = TcpListener::bind(("127.0.0.1", port.parse().expect("Couldn't parse port!")))?;
+            eprintln!("Listening on {}", listener.local_addr()?);
+            for stream in listener.incoming() {
+                let stream = stream?;
+                spawn_session(Arc::clone(&listing), budget, stream.try_clone()?, stream);
+            };
+        },
+        (None, Some(socket)) => {
+            remove_socket(Path::new(&socket));
+            let listener = UnixListener::bind(&socket)?;
+            eprintln!("Listening on {}", socket);
+
+            let on_interrupt = socket.clone();
+            if let Err(e) = ctrlc::set_handler(move || {
+                remove_socket(Path::new(&on_interrupt));
+                process::exit(130);
+            }) {
+                eprintln!("Couldn't catch Ctrl-C, so {} may be left behind: {}", socket, e);
+            };
+
+            let served = listener.incoming().try_for_each(|stream| -> io::Result<()> {
+                let stream = stream?;
+                spawn_session(Arc::clone(&listing), budget, stream.try_clone()?, stream);
+                Ok(())
+            });
+            remove_socket(Path::new(&socket));
+            served?;
+        },
+        _ => usage()
+    };
+
+    Ok(())
+}
@@ -0,0 +1,234 @@
+use crate::{run, Node, Program, State};
+
+
+// canonical programs from the puzzle text, plus one case for every opcode and
+// parameter mode that opcode accepts. an engine is anything that takes a
+// program and its inputs and runs it to completion, so these can be pointed at
+// any implementation, not just `run`
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Outcome {
+    pub output: Vec<i64>,
+    pub memory: Program
+}
+
+pub struct Case {
+    pub name: String,
+    pub program: Program,
+    pub input: Vec<i64>,
+    // None if the output doesn't matter for this case
+    pub output: Option<Vec<i64>>,
+    // cells that must hold these values after halting
+    pub memory: Vec<(usize, i64)>
+}
+
+impl Case {
+    pub fn outputs(name: &str, program: &[i64], input: &[i64], output: &[i64]) -> Self {
+        Case {
+            name: name.to_string(),
+            program: program.to_vec(),
+            input: input.to_vec(),
+            output: Some(output.to_vec()),
+            memory: Vec::new()
+        }
+    }
+
+    pub fn leaves(name: &str, program: &[i64], memory: &[(usize, i64)]) -> Self {
+        Case {
+            name: name.to_string(),
+            program: program.to_vec(),
+            input: Vec::new(),
+            output: None,
+            memory: memory.to_vec()
+        }
+    }
+
+    pub fn check<E>(&self, engine: E) -> Result<(), String>
+        where E: FnOnce(&Program, &[i64]) -> Result<Outcome, String>
+    {
+        let outcome = engine(&self.program, &self.input)
+            .map_err(|e| format!("{}: {}", self.name, e))?;
+
+        if let Some(output) = &self.output {
+            if *output != outcome.output {
+                return Err(format!("{}: expected output {:?}, got {:?}",
+                                   self.name, output, outcome.output));
+            };
+        };
+        for &(addr, value) in &self.memory {
+            let found = *outcome.memory.get(addr).unwrap_or(&0);
+            if found != value {
+                return Err(format!("{}: expected {} at address {}, found {}",
+                                   self.name, value, addr, found));
+            };
+        };
+
+        Ok(())
+    }
+}
+
+
+// runs a program through `run` until it halts, giving up after enough
+// instructions that only a broken engine would use them all
+pub fn reference(program: &Program, input: &[i64]) -> Result<Outcome, String> {
+    let mut node = Node::new('T', program);
+    node.input.extend(input);
+    node.budget = Some(10_000_000);
+
+    while node.state != State::Halted {
+        run(&mut node);
+        match node.state {
+            State::InputWait => return Err("ran out of input".to_string()),
+            State::BudgetExhausted => return Err("never halted".to_string()),
+            State::Faulted => return Err(node.fault.expect("No fault recorded!").to_string()),
+            _ => ()
+        };
+    };
+
+    Ok(Outcome { output: Vec::from(node.output), memory: node.program })
+}
+
+
+fn examples() -> Vec<Case> {
+    let compare_pos_eq = [3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8];
+    let compare_pos_lt = [3, 9, 7, 9, 10, 9, 4, 9, 99, -1, 8];
+    let compare_imm_eq = [3, 3, 1108, -1, 8, 3, 4, 3, 99];
+    let compare_imm_lt = [3, 3, 1107, -1, 8, 3, 4, 3, 99];
+    let jump_pos = [3, 12, 6, 12, 15, 1, 13, 14, 13, 4, 13, 99, -1, 0, 1, 9];
+    let jump_imm = [3, 3, 1105, -1, 9, 1101, 0, 0, 12, 4, 12, 99, 1];
+    let around_8 = [
+        3, 21, 1008, 21, 8, 20, 1005, 20, 22, 107, 8, 21, 20, 1006, 20, 31,
+        1106, 0, 36, 98, 0, 0, 1002, 21, 125, 20, 4, 20, 1105, 1, 46, 104,
+        999, 1105, 1, 46, 1101, 1000, 1, 20, 4, 20, 1105, 1, 46, 98, 99
+    ];
+    let quine = [109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99];
+
+    vec![
+        Case::leaves("day 2 add", &[1, 0, 0, 0, 99], &[(0, 2)]),
+        Case::leaves("day 2 mul", &[2, 3, 0, 3, 99], &[(3, 6)]),
+        Case::leaves("day 2 mul past the end", &[2, 4, 4, 5, 99, 0], &[(5, 9801)]),
+        Case::leaves("day 2 self-modifying", &[1, 1, 1, 4, 99, 5, 6, 0, 99], &[(0, 30), (4, 2)]),
+        Case::leaves("day 2 sample", &[1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50], &[(0, 3500), (3, 70)]),
+
+        Case::outputs("day 5 echo", &[3, 0, 4, 0, 99], &[-17], &[-17]),
+        Case::leaves("day 5 immediate mul", &[1002, 4, 3, 4, 33], &[(4, 99)]),
+        Case::leaves("day 5 negative immediate", &[1101, 100, -1, 4, 0], &[(4, 99)]),
+        Case::outputs("day 5 position eq 8 (equal)", &compare_pos_eq, &[8], &[1]),
+        Case::outputs("day 5 position eq 8 (not equal)", &compare_pos_eq, &[7], &[0]),
+        Case::outputs("day 5 position lt 8 (less)", &compare_pos_lt, &[7], &[1]),
+        Case::outputs("day 5 position lt 8 (not less)", &compare_pos_lt, &[8], &[0]),
+        Case::outputs("day 5 immediate eq 8 (equal)", &compare_imm_eq, &[8], &[1]),
+        Case::outputs("day 5 immediate eq 8 (not equal)", &compare_imm_eq, &[9], &[0]),
+        Case::outputs("day 5 immediate lt 8 (less)", &compare_imm_lt, &[-8], &[1]),
+        Case::outputs("day 5 immediate lt 8 (not less)", &compare_imm_lt, &[8], &[0]),
+        Case::outputs("day 5 position jump (zero)", &jump_pos, &[0], &[0]),
+        Case::outputs("day 5 position jump (nonzero)", &jump_pos, &[3], &[1]),
+        Case::outputs("day 5 immediate jump (zero)", &jump_imm, &[0], &[0]),
+        Case::outputs("day 5 immediate jump (nonzero)", &jump_imm, &[-3], &[1]),
+        Case::outputs("day 5 around 8 (below)", &around_8, &[7], &[999]),
+        Case::outputs("day 5 around 8 (equal)", &around_8, &[8], &[1000]),
+        Case::outputs("day 5 around 8 (above)", &around_8, &[9], &[1001]),
+
+        Case::outputs("day 9 quine", &quine, &[], &quine),
+        Case::outputs("day 9 16-digit product", &[1102, 34915192, 34915192, 7, 4, 7, 99, 0], &[], &[1219070632396864]),
+        Case::outputs("day 9 large immediate", &[104, 1125899906842624, 99], &[], &[1125899906842624]),
+    ]
+}
+
+type Semantics = fn(i64, i64) -> i64;
+
+const MODES: [(i64, &str); 3] = [(0, "pos"), (1, "imm"), (2, "rel")];
+
+// a parameter that reads `value`, which is stored at `addr` in case the mode
+// needs to find it there
+fn read_param(mode: i64, addr: i64, value: i64, rb: i64) -> i64 {
+    match mode {
+        0 => addr,
+        1 => value,
+        _ => addr - rb
+    }
+}
+
+fn op_modes() -> Vec<Case> {
+    let mut cases = Vec::new();
+
+    // [rbo 5] [op p1 p2 p3] [out W] [hlt] a b W
+    let rb = 5;
+    let ternary: [(i64, &str, Semantics); 4] = [
+        (1, "add", |a, b| a + b),
+        (2, "mul", |a, b| a * b),
+        (7, "ltn", |a, b| (a < b) as i64),
+        (8, "eql", |a, b| (a == b) as i64),
+    ];
+    for &(opcode, op, f) in &ternary {
+        for &(m1, mode1) in &MODES {
+            for &(m2, mode2) in &MODES {
+                for &(m3, mode3) in &[MODES[0], MODES[2]] {
+                    for &(a, b) in &[(6, 7), (7, 7), (-3, 5)] {
+                        let program = [
+                            109, rb,
+                            opcode + 100 * m1 + 1000 * m2 + 10000 * m3,
+                            read_param(m1, 9, a, rb), read_param(m2, 10, b, rb), read_param(m3, 11, 0, rb),
+                            4, 11,
+                            99,
+                            a, b, 0
+                        ];
+                        let name = format!("{} {} {} {} ({}, {})", op, mode1, mode2, mode3, a, b);
+                        cases.push(Case::outputs(&name, &program, &[], &[f(a, b)]));
+                    };
+                };
+            };
+        };
+    };
+
+    // [rbo 5] [inp p] [out 7] [hlt] W
+    for &(m, mode) in &[MODES[0], MODES[2]] {
+        let program = [109, rb, 3 + 100 * m, read_param(m, 7, 0, rb), 4, 7, 99, 0];
+        cases.push(Case::outputs(&format!("inp {}", mode), &program, &[42], &[42]));
+    };
+
+    // [rbo 2] [out p] [hlt] v
+    for &(m, mode) in &MODES {
+        let program = [109, 2, 4 + 100 * m, read_param(m, 5, -9, 2), 99, -9];
+        cases.push(Case::outputs(&format!("out {}", mode), &program, &[], &[-9]));
+    };
+
+    // [rbo 3] [jmp p1 p2] [out 0] [hlt] [out 1] [hlt] cond target
+    for &(opcode, op) in &[(5, "jnz"), (6, "jez")] {
+        for &(m1, mode1) in &MODES {
+            for &(m2, mode2) in &MODES {
+                for &cond in &[0, 5] {
+                    let program = [
+                        109, 3,
+                        opcode + 100 * m1 + 1000 * m2,
+                        read_param(m1, 11, cond, 3), read_param(m2, 12, 8, 3),
+                        104, 0, 99,
+                        104, 1, 99,
+                        cond, 8
+                    ];
+                    let taken = (cond != 0) == (opcode == 5);
+                    let name = format!("{} {} {} ({})", op, mode1, mode2, cond);
+                    cases.push(Case::outputs(&name, &program, &[], &[taken as i64]));
+                };
+            };
+        };
+    };
+
+    // [rbo 3] [rbo p] [out rel 0] [hlt] 5 77; either way the base ends up at 8
+    for &(m, mode) in &MODES {
+        let program = [109, 3, 9 + 100 * m, read_param(m, 7, 5, 3), 204, 0, 99, 5, 77];
+        cases.push(Case::outputs(&format!("rbo {}", mode), &program, &[], &[77]));
+    };
+    cases.push(Case::outputs("rbo negative", &[109, 10, 109, -4, 204, 1, 99, 31], &[], &[31]));
+
+    cases.push(Case::outputs("hlt", &[99, 104, 1], &[], &[]));
+    cases.push(Case::leaves("rel write past the end", &[109, 20, 21101, 3, 4, 5, 99], &[(25, 7)]));
+
+    cases
+}
+
+pub fn cases() -> Vec<Case> {
+    let mut cases = examples();
+    cases.extend(op_modes());
+    cases
+}
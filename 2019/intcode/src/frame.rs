@@ -0,0 +1,62 @@
+use std::fmt;
+use std::error::Error;
+
+use crate::{Node, State, Word};
+
+
+type Decoder<T, W> = Box<dyn Fn(&[W]) -> T>;
+
+// protocols where a program's outputs come in fixed-size groups, like (x, y,
+// tile) from an arcade cabinet. a framing says how big a group is and how to
+// turn one into something the driver cares about
+pub struct Framing<T, W = i64> {
+    size: usize,
+    decode: Decoder<T, W>
+}
+
+// a node halted with only part of a frame in its output queue
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartialFrame<W = i64> {
+    pub label: char,
+    pub size: usize,
+    pub values: Vec<W>
+}
+
+impl<W: Word> fmt::Display for PartialFrame<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Node {} halted partway through a frame, with {} of {} values: {:?}",
+               self.label, self.values.len(), self.size, self.values)
+    }
+}
+
+impl<W: Word> Error for PartialFrame<W> {}
+
+impl<T, W: Word> Framing<T, W> {
+    pub fn new<F>(size: usize, decode: F) -> Self
+        where F: Fn(&[W]) -> T + 'static
+    {
+        assert!(size > 0, "Frames can't be empty!");
+        Framing { size, decode: Box::new(decode) }
+    }
+
+    // decodes every complete frame waiting in the node's output. whatever's
+    // left over stays queued until the rest of its frame arrives, unless the
+    // node has halted and it never will
+    pub fn take(&self, node: &mut Node<W>) -> Result<Vec<T>, PartialFrame<W>> {
+        let mut events = Vec::new();
+        while node.output.len() >= self.size {
+            let frame = node.output.drain(..self.size).collect::<Vec<_>>();
+            events.push((self.decode)(&frame));
+        };
+
+        if node.state == State::Halted && !node.output.is_empty() {
+            return Err(PartialFrame {
+                label: node.label,
+                size: self.size,
+                values: node.output.drain(..).collect()
+            });
+        };
+
+        Ok(events)
+    }
+}
@@ -0,0 +1,450 @@
+use std::io;
+
+use std::fmt;
+use std::error::Error;
+use std::cmp::{PartialEq, Eq};
+use std::collections::{HashSet, VecDeque};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use log::{debug, trace, log_enabled};
+use log::Level::Trace;
+use num_bigint::BigInt;
+
+mod frame;
+mod load;
+mod options;
+mod tape;
+mod word;
+pub mod conformance;
+pub mod repl;
+pub mod scan;
+pub mod serve;
+pub use frame::{Framing, PartialFrame};
+pub use load::{load, load_setup, parse, parse_setup, Header, Listing, LoadError};
+pub use options::{options, take_option};
+pub use tape::{load_tape, parse_tape, replay, Divergence, Entry, Tape};
+pub use word::{Arithmetic, Word};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Pos = 0,
+    Imm = 1,
+    Rel = 2
+}
+
+impl Mode {
+    fn decode(i: i64) -> Option<Self> {
+        match i {
+            0 => Some(Mode::Pos),
+            1 => Some(Mode::Imm),
+            2 => Some(Mode::Rel),
+            _ => None
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Add = 1,
+    Mul = 2,
+    Inp = 3,
+    Out = 4,
+    Jnz = 5,
+    Jez = 6,
+    Ltn = 7,
+    Eql = 8,
+    Rbo = 9,
+    Hlt = 99
+}
+
+impl Op {
+    fn decode(i: i64) -> Option<Self> {
+        match i {
+            1 => Some(Op::Add),
+            2 => Some(Op::Mul),
+            3 => Some(Op::Inp),
+            4 => Some(Op::Out),
+            5 => Some(Op::Jnz),
+            6 => Some(Op::Jez),
+            7 => Some(Op::Ltn),
+            8 => Some(Op::Eql),
+            9 => Some(Op::Rbo),
+            99 => Some(Op::Hlt),
+            _ => None
+        }
+    }
+}
+
+
+struct Instr {
+    op: Op,
+    modes: Vec<Mode>,
+    arity: usize
+}
+
+impl Instr {
+    fn decode(i: i64) -> Option<Self> {
+        if !(0..100000).contains(&i) {
+            return None;
+        };
+        let op = Op::decode(i % 100)?;
+
+        let mode1 = Mode::decode(i / 100 % 10)?;
+        let mode2 = Mode::decode(i / 1000 % 10)?;
+        let mode3 = Mode::decode(i / 10000 % 10)?;
+        let modes = vec!(mode1, mode2, mode3);
+
+        let arity = match op {
+            Op::Add | Op::Mul => 3,
+            Op::Inp | Op::Out => 1,
+            Op::Jnz | Op::Jez => 2,
+            Op::Ltn | Op::Eql => 3,
+            Op::Rbo => 1,
+            Op::Hlt => 0
+        };
+
+        Some(Instr { op, modes, arity })
+    }
+
+    // checked for range first, as a big word can't always be an i64
+    fn from_word<W: Word>(word: &W) -> Option<Self> {
+        if *word >= W::from_i64(0) && *word < W::from_i64(100000) {
+            Instr::decode(word.to_i64())
+        } else { None }
+    }
+}
+
+impl fmt::Debug for Instr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}{:?}{:?}{:?}", self.modes[2], self.modes[1], self.modes[0], self.op)
+    }
+}
+
+// one instruction as text, and how many cells it takes up. cells that don't
+// decode as an instruction (usually data) come out as a bare value
+pub fn disassemble<W: Word>(program: &[W], addr: usize) -> (String, usize) {
+    let cell = |i: usize| program.get(i).cloned().unwrap_or_else(|| W::from_i64(0));
+    let opcode = cell(addr);
+    match Instr::from_word(&opcode) {
+        Some(instr) => {
+            let params = (0..instr.arity)
+                .map(|i| {
+                    let raw = cell(addr + i + 1);
+                    match instr.modes[i] {
+                        Mode::Pos => format!("[{}]", raw),
+                        Mode::Imm => format!("{}", raw),
+                        Mode::Rel if raw < W::from_i64(0) => format!("[rb{}]", raw),
+                        Mode::Rel => format!("[rb+{}]", raw)
+                    }
+                })
+                .collect::<Vec<_>>();
+            let op = format!("{:?}", instr.op).to_lowercase();
+            (format!("{} {}", op, params.join(", ")).trim_end().to_string(), instr.arity + 1)
+        },
+        None => (format!("data {}", opcode), 1)
+    }
+}
+
+
+pub type Program<W = i64> = Vec<W>;
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum State {
+    Boot,
+    Running,
+    InputWait,
+    OutputYield,
+    BudgetExhausted,
+    LoopDetected,
+    Faulted,
+    Halted
+}
+
+// an Add or Mul whose result didn't fit, under checked arithmetic
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overflow<W = i64> {
+    pub ip: usize,
+    pub op: Op,
+    pub operands: (W, W)
+}
+
+impl<W: Word> fmt::Display for Overflow<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} {} {} overflowed at ip {}", self.op, self.operands.0, self.operands.1, self.ip)
+    }
+}
+
+impl<W: Word> Error for Overflow<W> {}
+
+// why a node stopped in State::Faulted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fault<W = i64> {
+    Overflow(Overflow<W>),
+    UnknownOpcode { ip: usize, opcode: W }
+}
+
+impl<W: Word> fmt::Display for Fault<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Fault::Overflow(overflow) => write!(f, "{}", overflow),
+            Fault::UnknownOpcode { ip, opcode } => write!(f, "unknown opcode {} at ip {}", opcode, ip)
+        }
+    }
+}
+
+impl<W: Word> Error for Fault<W> {}
+
+pub struct Node<W = i64> {
+    pub label: char,
+
+    pub state: State,
+    pub program: Program<W>,
+    pub ip: usize,
+    pub rb: usize,
+
+    pub input: VecDeque<W>,
+    pub output: VecDeque<W>,
+
+    pub arithmetic: Arithmetic,
+    // set when the node stops in State::Faulted
+    pub fault: Option<Fault<W>>,
+    // instructions left before `run` hands back control, if limited
+    pub budget: Option<usize>,
+    // whether to watch backward jumps for a repeat of an earlier machine state
+    pub detect_loops: bool,
+    seen: HashSet<u64>,
+    // instructions executed so far
+    pub steps: usize,
+    // if set, every input read and output written gets logged here
+    pub tape: Option<Tape<W>>
+}
+
+impl<W: Word> Node<W> {
+    pub fn new(label: char, program: &Program<W>) -> Self {
+        Node {
+            label,
+
+            state: State::Boot,
+            program: program.to_vec(),
+            ip: 0,
+            rb: 0,
+
+            input: VecDeque::new(),
+            output: VecDeque::new(),
+
+            arithmetic: Arithmetic::Wrapping,
+            fault: None,
+            budget: None,
+            detect_loops: false,
+            seen: HashSet::new(),
+            steps: 0,
+            tape: None
+        }
+    }
+
+    fn fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        (self.ip, self.rb, &self.program).hash(&mut hasher);
+        hasher.finish()
+    }
+
+    // true if this jump lands the machine somewhere it's already been since it
+    // last read an input or wrote an output, in which case it's never going to
+    // get out
+    fn revisiting(&mut self, from: usize) -> bool {
+        self.detect_loops && self.ip <= from && !self.seen.insert(self.fingerprint())
+    }
+
+    pub fn get(&self, index: usize) -> W {
+        if index < self.program.len() {
+            self.program[index].clone()
+        } else { W::from_i64(0) }
+    }
+    pub fn set(&mut self, index: usize, value: W) {
+        if index >= self.program.len() {
+            self.program.resize(index + 1, W::from_i64(0));
+        };
+        self.program[index] = value;
+    }
+}
+
+impl<W: Word> fmt::Debug for Node<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Node {} in state {:?}\n\
+                    \tInstruction pointer: {}, relative base: {}\n\
+                    \tInput: {:?}\n\
+                    \tOutput: {:?}",
+                self.label, self.state,
+                self.ip, self.rb,
+                self.input,
+                self.output)
+    }
+}
+
+// for running a program under big-integer arithmetic
+pub fn widen(program: &Program) -> Program<BigInt> {
+    program.iter().map(|&i| BigInt::from(i)).collect()
+}
+
+struct Param<W> {
+    mode: Mode,
+    raw: W,
+    as_idx: Option<usize>,
+    deref: W
+}
+
+impl<W: Word> fmt::Debug for Param<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({:?}, {}, {})", self.mode, self.raw, self.deref)
+    }
+}
+
+pub fn run<W: Word>(node: &mut Node<W>) {
+    // a fault only ever describes the run that stopped on it
+    node.fault = None;
+    loop {
+        node.state = State::Running;
+
+        if let Some(budget) = node.budget {
+            if budget == 0 {
+                node.state = State::BudgetExhausted;
+                return;
+            };
+            node.budget = Some(budget - 1);
+        };
+        node.steps += 1;
+
+        let opcode = node.get(node.ip);
+        let instr = match Instr::from_word(&opcode) {
+            Some(instr) => instr,
+            None => {
+                node.fault = Some(Fault::UnknownOpcode { ip: node.ip, opcode });
+                node.state = State::Faulted;
+                return;
+            }
+        };
+
+        let mut params = Vec::new();
+        for i in 0..instr.arity {
+            let mode = instr.modes[i];
+            let raw = node.get(node.ip + i + 1);
+            let as_idx = match mode {
+                Mode::Pos => Some(raw.to_i64() as usize),
+                Mode::Rel => Some(((node.rb as i64) + raw.to_i64()) as usize),
+                Mode::Imm => None
+            };
+            params.push(Param {
+                mode,
+                deref: match as_idx {
+                    Some(idx) => node.get(idx),
+                    None => raw.clone()
+                },
+                raw,
+                as_idx
+            });
+        };
+
+        let ternary_setup = || (&params[0].deref, &params[1].deref, params[2].as_idx.expect("No index set!"));
+
+        trace!("{:?}", node);
+        trace!("{:?}", node.program);
+        trace!("ip: {} -> {}", node.ip, node.get(node.ip));
+        debug!("About to execute instruction {:?} with parameters {:?}",
+               instr.op, params);
+
+        if log_enabled!(Trace) {
+            println!("Press Enter to execute");
+            let _ = io::stdin().read_line(&mut String::new()).expect("Couldn't read line!");
+        };
+
+        let mut jmp_occurred = false;
+        match instr.op {
+            Op::Add | Op::Mul => {
+                let (val1, val2, ret_idx) = ternary_setup();
+                let ret = match instr.op {
+                    Op::Add => val1.add(val2, node.arithmetic),
+                    _ => val1.mul(val2, node.arithmetic)
+                };
+                match ret {
+                    Some(ret) => node.set(ret_idx, ret),
+                    None => {
+                        node.fault = Some(Fault::Overflow(Overflow {
+                            ip: node.ip,
+                            op: instr.op,
+                            operands: (val1.clone(), val2.clone())
+                        }));
+                        node.state = State::Faulted;
+                        return;
+                    }
+                };
+            },
+            Op::Inp => {
+                match node.input.pop_front() {
+                    Some(input) => {
+                        if let Some(tape) = &mut node.tape {
+                            tape.entries.push(Entry::Input { step: node.steps, value: input.clone() });
+                        };
+                        let idx = params[0].as_idx.expect("No index set!");
+                        node.set(idx, input);
+                        node.seen.clear();
+                    },
+                    None => {
+                        // waiting on input doesn't use up the budget, or count
+                        // as a step
+                        node.budget = node.budget.map(|budget| budget + 1);
+                        node.steps -= 1;
+                        node.state = State::InputWait;
+                        return;
+                    }
+                };
+            },
+            Op::Out => {
+                let output = params[0].deref.clone();
+                if let Some(tape) = &mut node.tape {
+                    tape.entries.push(Entry::Output { step: node.steps, value: output.clone() });
+                };
+                node.output.push_back(output);
+                node.seen.clear();
+                node.state = State::OutputYield;
+                node.ip += instr.arity + 1;
+                return;
+            },
+            Op::Jnz | Op::Jez => {
+                let val = &params[0].deref;
+                if (*val != W::from_i64(0)) == (instr.op == Op::Jnz) {
+                    let from = node.ip;
+                    node.ip = params[1].deref.to_i64() as usize;
+                    jmp_occurred = true;
+                    if node.revisiting(from) {
+                        node.state = State::LoopDetected;
+                        return;
+                    };
+                };
+            },
+            Op::Ltn => {
+                let (val1, val2, ret_idx) = ternary_setup();
+                let ret = if val1 < val2 { 1 } else { 0 };
+                node.set(ret_idx, W::from_i64(ret));
+            },
+            Op::Eql => {
+                let (val1, val2, ret_idx) = ternary_setup();
+                let ret = if val1 == val2 { 1 } else { 0 };
+                node.set(ret_idx, W::from_i64(ret));
+            },
+            Op::Rbo => {
+                node.rb = ((node.rb as i64) + params[0].deref.to_i64()) as usize;
+            },
+            Op::Hlt => {
+                node.state = State::Halted;
+                return;
+            }
+        };
+
+        if !jmp_occurred {
+            node.ip += instr.arity + 1;
+        };
+    }
+}
@@ -0,0 +1,242 @@
+use std::fs;
+use std::io;
+
+use std::fmt;
+use std::error::Error;
+
+use crate::{Node, Program, Word};
+
+
+// program files are comma- and/or whitespace-separated integers. `#` starts a
+// comment that runs to the end of the line, and an optional header of
+// directives may come before the first integer:
+//
+//     @name arcade
+//     @patch 0=2
+//     @input 1, 0, -1
+//     1,380,379,385,1008,2531,...
+//
+// a setup script is just the header on its own, for configuring a machine
+// without touching its program file
+
+#[derive(Debug, Clone, Default)]
+pub struct Header {
+    pub name: Option<String>,
+    pub patches: Vec<(usize, i64)>,
+    pub inputs: Vec<i64>
+}
+
+impl Header {
+    // layers `over` on top of this header: its patches land last, and its
+    // inputs (if it has any) replace ours rather than queueing behind them
+    pub fn merge(&mut self, over: Header) {
+        if over.name.is_some() {
+            self.name = over.name;
+        };
+        self.patches.extend(over.patches);
+        if !over.inputs.is_empty() {
+            self.inputs = over.inputs;
+        };
+    }
+
+    // applied over whatever the driver has already set up, by the same rules
+    // as `merge`
+    pub fn apply<W: Word>(&self, node: &mut Node<W>) {
+        for &(addr, value) in &self.patches {
+            node.set(addr, W::from_i64(value));
+        };
+        if !self.inputs.is_empty() {
+            node.input = self.inputs.iter().map(|&i| W::from_i64(i)).collect();
+        };
+    }
+}
+
+#[derive(Debug)]
+pub struct Listing {
+    pub header: Header,
+    pub program: Program
+}
+
+impl Listing {
+    pub fn patched(&self) -> Program {
+        let mut program = self.program.to_vec();
+        for &(addr, value) in &self.header.patches {
+            if addr >= program.len() {
+                program.resize(addr + 1, 0);
+            };
+            program[addr] = value;
+        };
+
+        program
+    }
+
+    pub fn boot(&self, label: char) -> Node {
+        let mut node = Node::new(label, &self.program);
+        self.header.apply(&mut node);
+        node
+    }
+}
+
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct LoadError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl Error for LoadError {}
+
+
+// (column, token) pairs, split on commas and whitespace
+fn tokens(text: &str) -> Vec<(usize, &str)> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (i, c) in text.char_indices().chain(vec![(text.len(), ',')]) {
+        if c == ',' || c.is_whitespace() {
+            if let Some(s) = start.take() {
+                tokens.push((s + 1, &text[s..i]));
+            };
+        } else if start.is_none() {
+            start = Some(i);
+        };
+    };
+
+    tokens
+}
+
+fn parse_value(line: usize, column: usize, token: &str) -> Result<i64, LoadError> {
+    token.parse::<i64>().map_err(|_| LoadError {
+        line, column,
+        message: format!("expected an integer, found {:?}", token)
+    })
+}
+
+pub(crate) fn parse_directive(header: &mut Header, line: usize, text: &str) -> Result<(), LoadError> {
+    let (name, args) = match text.find(char::is_whitespace) {
+        Some(i) => (&text[1..i], &text[i..]),
+        None => (&text[1..], "")
+    };
+    let offset = text.len() - args.len();
+
+    match name {
+        "name" => {
+            header.name = Some(args.trim().to_string());
+        },
+        "patch" => {
+            for (column, token) in tokens(args) {
+                let column = column + offset;
+                let mut sides = token.splitn(2, '=');
+                let (addr, value) = match (sides.next(), sides.next()) {
+                    (Some(addr), Some(value)) => (addr, value),
+                    _ => return Err(LoadError {
+                        line, column,
+                        message: format!("expected addr=value, found {:?}", token)
+                    })
+                };
+                let addr = addr.parse::<usize>().map_err(|_| LoadError {
+                    line, column,
+                    message: format!("expected an address, found {:?}", addr)
+                })?;
+                let value = parse_value(line, column + token.find('=').unwrap() + 1, value)?;
+                header.patches.push((addr, value));
+            };
+        },
+        "input" => {
+            for (column, token) in tokens(args) {
+                header.inputs.push(parse_value(line, column + offset, token)?);
+            };
+        },
+        _ => return Err(LoadError {
+            line, column: 1,
+            message: format!("unknown directive @{}", name)
+        })
+    };
+
+    Ok(())
+}
+
+// a line with its comment and surrounding whitespace stripped, and how much
+// whitespace there was in front
+fn code(raw: &str) -> (usize, &str) {
+    let code = match raw.find('#') {
+        Some(comment) => &raw[..comment],
+        None => raw
+    };
+    (code.len() - code.trim_start().len(), code.trim())
+}
+
+pub fn parse(text: &str) -> Result<Listing, LoadError> {
+    let mut header = Header::default();
+    let mut program = Vec::new();
+
+    for (i, raw) in text.lines().enumerate() {
+        let line = i + 1;
+        let (indent, code) = code(raw);
+
+        if code.starts_with('@') {
+            if !program.is_empty() {
+                return Err(LoadError {
+                    line, column: indent + 1,
+                    message: "directives must come before the program".to_string()
+                });
+            };
+            parse_directive(&mut header, line, code)
+                .map_err(|e| LoadError { column: e.column + indent, ..e })?;
+        } else {
+            for (column, token) in tokens(code) {
+                program.push(parse_value(line, column + indent, token)?);
+            };
+        };
+    };
+
+    if program.is_empty() {
+        return Err(LoadError {
+            line: text.lines().count().max(1), column: 1,
+            message: "no program found".to_string()
+        });
+    };
+
+    Ok(Listing { header, program })
+}
+
+pub fn parse_setup(text: &str) -> Result<Header, LoadError> {
+    let mut header = Header::default();
+
+    for (i, raw) in text.lines().enumerate() {
+        let line = i + 1;
+        let (indent, code) = code(raw);
+
+        if code.starts_with('@') {
+            parse_directive(&mut header, line, code)
+                .map_err(|e| LoadError { column: e.column + indent, ..e })?;
+        } else if !code.is_empty() {
+            return Err(LoadError {
+                line, column: indent + 1,
+                message: "expected a directive".to_string()
+            });
+        };
+    };
+
+    Ok(header)
+}
+
+fn invalid(path: &str, e: LoadError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("{}, {}", path, e))
+}
+
+pub fn load(path: &str) -> Result<Listing, io::Error> {
+    let contents = fs::read_to_string(path)?;
+    parse(&contents).map_err(|e| invalid(path, e))
+}
+
+pub fn load_setup(path: &str) -> Result<Header, io::Error> {
+    let contents = fs::read_to_string(path)?;
+    parse_setup(&contents).map_err(|e| invalid(path, e))
+}
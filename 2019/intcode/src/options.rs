@@ -0,0 +1,64 @@
+use std::io;
+
+use crate::load::{load_setup, parse_directive, Header};
+
+
+// command-line options every intcode binary accepts, to set a machine up
+// differently without recompiling. they're applied in order, each on top of
+// the last (see `Header::merge`), and on top of the program file's header:
+//
+//     --patch 1=12,2=2     overwrite cells before the first run
+//     --input 1,0,-1       queue these inputs instead of the usual ones
+//     --setup <file>       read @patch and @input directives from a file
+//
+// returns the remaining arguments, in order, along with the combined setup
+pub fn options(args: Vec<String>) -> Result<(Vec<String>, Header), io::Error> {
+    let mut rest = Vec::new();
+    let mut setup = Header::default();
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let directive = match arg.as_str() {
+            "--patch" => "patch",
+            "--input" => "input",
+            "--setup" => {
+                let path = args.next().ok_or_else(|| missing(&arg))?;
+                setup.merge(load_setup(&path)?);
+                continue;
+            },
+            _ => {
+                rest.push(arg);
+                continue;
+            }
+        };
+
+        let value = args.next().ok_or_else(|| missing(&arg))?;
+        let mut given = Header::default();
+        parse_directive(&mut given, 1, &format!("@{} {}", directive, value))
+            .map_err(|e| io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} {}: {}", arg, value, e.message)
+            ))?;
+        setup.merge(given);
+    };
+
+    Ok((rest, setup))
+}
+
+// pulls `flag <value>` out of the arguments, for options a driver only
+// sometimes wants, like where to record a tape. the flag without a value is
+// an error
+pub fn take_option(args: &mut Vec<String>, flag: &str) -> Result<Option<String>, String> {
+    match args.iter().position(|arg| arg == flag) {
+        Some(i) if i + 1 < args.len() => {
+            args.remove(i);
+            Ok(Some(args.remove(i)))
+        },
+        Some(_) => Err(missing(flag).to_string()),
+        None => Ok(None)
+    }
+}
+
+fn missing(flag: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, format!("{} needs a value", flag))
+}
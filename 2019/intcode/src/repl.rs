@@ -0,0 +1,155 @@
+use std::io::{self, Write};
+use std::str::FromStr;
+
+use crate::{disassemble, run, Arithmetic, Listing, Node, State};
+
+
+const HELP: &str = "\
+peek <addr> [count]     show memory
+poke <addr> <value>...  overwrite memory, starting at addr
+ip [addr]               show or move the instruction pointer
+rb [addr]               show or move the relative base
+arith [mode]            show or set overflow mode, wrapping or checked
+input <value>...        queue inputs
+run                     run until the next output, input request or halt
+step [n]                run at most n instructions (default 1)
+dis [addr] [count]      disassemble, from the instruction pointer by default
+node                    summarize the node
+reset                   start over from the loaded program
+load <file>             load another program
+quit";
+
+// an interactive shell over a single node, for poking at a program by hand
+pub struct Shell {
+    listing: Listing,
+    node: Node
+}
+
+fn parse_all<T: FromStr>(words: &[&str]) -> Result<Vec<T>, String> {
+    words
+        .iter()
+        .map(|word| word.parse::<T>().map_err(|_| format!("bad number {:?}", word)))
+        .collect()
+}
+
+impl Shell {
+    pub fn new(listing: Listing) -> Self {
+        let node = listing.boot('R');
+        Shell { listing, node }
+    }
+
+    pub fn node(&self) -> &Node {
+        &self.node
+    }
+
+    // runs once, with an instruction budget if given, then reports where the
+    // node stopped and what it's about to do
+    fn advance<W: Write>(&mut self, budget: Option<usize>, out: &mut W) -> io::Result<()> {
+        if self.node.state == State::Halted {
+            return writeln!(out, "halted");
+        };
+
+        self.node.budget = budget;
+        run(&mut self.node);
+        self.node.budget = None;
+
+        if self.node.state == State::OutputYield {
+            let output = self.node.output.pop_front().expect("No output after yield!");
+            writeln!(out, "output {}", output)?;
+        };
+        if let Some(fault) = &self.node.fault {
+            writeln!(out, "fault {}", fault)?;
+        };
+        let (next, _) = disassemble(&self.node.program, self.node.ip);
+        writeln!(out, "{:?} at {}: {}", self.node.state, self.node.ip, next)
+    }
+
+    fn peek<W: Write>(&self, addr: usize, count: usize, out: &mut W) -> io::Result<()> {
+        // eight cells to a row
+        for row in (addr..addr + count).step_by(8) {
+            let cells = (row..(row + 8).min(addr + count))
+                .map(|i| self.node.get(i).to_string())
+                .collect::<Vec<_>>();
+            writeln!(out, "{:>6}: {}", row, cells.join(" "))?;
+        };
+        Ok(())
+    }
+
+    fn dis<W: Write>(&self, addr: usize, count: usize, out: &mut W) -> io::Result<()> {
+        let mut addr = addr;
+        for _ in 0..count {
+            let marker = if addr == self.node.ip { '>' } else { ' ' };
+            let (text, width) = disassemble(&self.node.program, addr);
+            writeln!(out, "{} {:>6}  {}", marker, addr, text)?;
+            addr += width;
+        };
+        Ok(())
+    }
+
+    fn command<W: Write>(&mut self, command: &str, args: &[&str], out: &mut W) -> Result<(), String> {
+        let io = |e: io::Error| e.to_string();
+        match (command, args.len()) {
+            ("help", _) => writeln!(out, "{}", HELP).map_err(io)?,
+            ("peek", 1) | ("peek", 2) => {
+                let args = parse_all::<usize>(args)?;
+                self.peek(args[0], *args.get(1).unwrap_or(&1), out).map_err(io)?;
+            },
+            ("poke", n) if n >= 2 => {
+                let addr = parse_all::<usize>(&args[..1])?[0];
+                for (i, value) in parse_all::<i64>(&args[1..])?.into_iter().enumerate() {
+                    self.node.set(addr + i, value);
+                };
+            },
+            ("ip", 0) => writeln!(out, "{}", self.node.ip).map_err(io)?,
+            ("ip", 1) => self.node.ip = parse_all::<usize>(args)?[0],
+            ("rb", 0) => writeln!(out, "{}", self.node.rb).map_err(io)?,
+            ("rb", 1) => self.node.rb = parse_all::<usize>(args)?[0],
+            ("arith", 0) => writeln!(out, "{:?}", self.node.arithmetic).map_err(io)?,
+            ("arith", 1) => self.node.arithmetic = match args[0] {
+                "wrapping" => Arithmetic::Wrapping,
+                "checked" => Arithmetic::Checked,
+                other => return Err(format!("no {} arithmetic, only wrapping or checked", other))
+            },
+            ("input", n) if n >= 1 => self.node.input.extend(parse_all::<i64>(args)?),
+            ("run", 0) => self.advance(None, out).map_err(io)?,
+            ("step", 0) | ("step", 1) => {
+                let steps = parse_all::<usize>(args)?.first().cloned().unwrap_or(1);
+                self.advance(Some(steps), out).map_err(io)?;
+            },
+            ("dis", n) if n <= 2 => {
+                let args = parse_all::<usize>(args)?;
+                let addr = args.first().cloned().unwrap_or(self.node.ip);
+                self.dis(addr, *args.get(1).unwrap_or(&10), out).map_err(io)?;
+            },
+            ("node", 0) => writeln!(out, "{:?}", self.node).map_err(io)?,
+            ("reset", 0) => self.node = self.listing.boot('R'),
+            ("load", 1) => {
+                self.listing = crate::load(args[0]).map_err(io)?;
+                self.node = self.listing.boot('R');
+            },
+            ("peek", _) | ("poke", _) | ("ip", _) | ("rb", _) | ("arith", _) | ("input", _) | ("run", _) |
+            ("step", _) | ("dis", _) | ("node", _) | ("reset", _) | ("load", _) =>
+                return Err(format!("wrong number of arguments to {}, see help", command)),
+            _ => return Err(format!("unknown command {}, see help", command))
+        };
+
+        Ok(())
+    }
+
+    // false once the user has asked to quit
+    pub fn handle<W: Write>(&mut self, line: &str, out: &mut W) -> io::Result<bool> {
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        match words.split_first() {
+            None => (),
+            Some((&"quit", _)) => return Ok(false),
+            Some((command, args)) => {
+                if let Err(e) = self.command(command, args, out) {
+                    writeln!(out, "error {}", e)?;
+                };
+            }
+        };
+
+        out.flush()?;
+        Ok(true)
+    }
+}
@@ -0,0 +1,86 @@
+use std::collections::BTreeMap;
+
+use crate::{Node, Program, Word};
+
+
+// for finding where a program keeps some piece of state, the way a game
+// trainer would: snapshot memory, let the program run, keep only the cells
+// that behaved the way the state should have, and repeat until few are left
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Filter<W = i64> {
+    Changed,
+    Unchanged,
+    Increased,
+    Decreased,
+    Equal(W)
+}
+
+impl<W: Word> Filter<W> {
+    fn keeps(&self, before: &W, after: &W) -> bool {
+        match self {
+            Filter::Changed => after != before,
+            Filter::Unchanged => after == before,
+            Filter::Increased => after > before,
+            Filter::Decreased => after < before,
+            Filter::Equal(value) => after == value
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Scanner<W = i64> {
+    snapshot: Program<W>,
+    candidates: Vec<usize>
+}
+
+impl<W: Word> Scanner<W> {
+    // every cell the node has starts out as a candidate
+    pub fn new(node: &Node<W>) -> Self {
+        Scanner { snapshot: node.program.clone(), candidates: (0..node.program.len()).collect() }
+    }
+
+    // keeps the candidates that pass the filter, comparing against the last
+    // snapshot, then takes a new snapshot
+    pub fn filter(&mut self, node: &Node<W>, filter: &Filter<W>) -> &[usize] {
+        let zero = W::from_i64(0);
+        let snapshot = &self.snapshot;
+        self.candidates.retain(|&addr| {
+            let before = snapshot.get(addr).unwrap_or(&zero);
+            filter.keeps(before, &node.get(addr))
+        });
+        self.snapshot = node.program.clone();
+        &self.candidates
+    }
+
+    pub fn candidates(&self) -> &[usize] {
+        &self.candidates
+    }
+}
+
+
+// cells held at a fixed value. the program can still write to them, so the
+// values have to be put back between every `run` call
+#[derive(Debug, Default)]
+pub struct Freezer<W = i64> {
+    cells: BTreeMap<usize, W>
+}
+
+impl<W: Word> Freezer<W> {
+    pub fn new() -> Self {
+        Freezer { cells: BTreeMap::new() }
+    }
+
+    pub fn freeze(&mut self, addr: usize, value: W) {
+        self.cells.insert(addr, value);
+    }
+
+    pub fn thaw(&mut self, addr: usize) {
+        self.cells.remove(&addr);
+    }
+
+    pub fn apply(&self, node: &mut Node<W>) {
+        for (&addr, value) in &self.cells {
+            node.set(addr, value.clone());
+        };
+    }
+}
@@ -0,0 +1,107 @@
+use std::io::{self, BufRead, Write};
+
+use crate::{run, Listing, Node, State};
+
+
+// line-based protocol for driving a node from another process. every reply
+// ends with a `state` line, so clients can read until they see one:
+//
+//     > input 1 2           queue inputs, then run until blocked
+//     < output 3
+//     < state InputWait
+//     > run                 run until blocked (input needed, halted, ...)
+//     > step                run until the next output, input or halt
+//     > snapshot            dump registers and memory
+//     < snapshot ip 4 rb 0 memory 3,0,4,0,99
+//     < state InputWait
+//     > reset               start over from the loaded program
+//     > quit
+
+pub struct Session<'a> {
+    listing: &'a Listing,
+    budget: Option<usize>,
+    node: Node
+}
+
+impl<'a> Session<'a> {
+    pub fn new(listing: &'a Listing, budget: Option<usize>) -> Self {
+        Session { listing, budget, node: listing.boot('S') }
+    }
+
+    fn advance<W: Write>(&mut self, out: &mut W, until_blocked: bool) -> io::Result<()> {
+        self.node.budget = self.budget;
+        loop {
+            run(&mut self.node);
+            match self.node.state {
+                State::OutputYield => {
+                    let output = self.node.output.pop_front().expect("No output after yield!");
+                    writeln!(out, "output {}", output)?;
+                    if !until_blocked {
+                        return Ok(());
+                    };
+                },
+                State::Faulted => {
+                    let fault = self.node.fault.as_ref().expect("No fault recorded!");
+                    return writeln!(out, "error {}", fault);
+                },
+                _ => return Ok(())
+            };
+        }
+    }
+
+    fn snapshot<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        let memory = self.node.program
+            .iter()
+            .map(|cell| cell.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        writeln!(out, "snapshot ip {} rb {} memory {}", self.node.ip, self.node.rb, memory)
+    }
+
+    // false once the client has asked to quit
+    pub fn handle<W: Write>(&mut self, line: &str, out: &mut W) -> io::Result<bool> {
+        let mut words = line.split_whitespace();
+        match words.next() {
+            None => return Ok(true),
+            Some("quit") => return Ok(false),
+            Some("input") => {
+                let values = words.map(|word| word.parse::<i64>()).collect::<Result<Vec<_>, _>>();
+                match values {
+                    Ok(values) => {
+                        self.node.input.extend(values);
+                        self.advance(out, true)?;
+                    },
+                    Err(e) => writeln!(out, "error bad input: {}", e)?
+                };
+            },
+            Some("run") => self.advance(out, true)?,
+            Some("step") => self.advance(out, false)?,
+            Some("snapshot") => self.snapshot(out)?,
+            Some("reset") => {
+                self.node = self.listing.boot('S');
+            },
+            Some(command) => writeln!(out, "error unknown command {}", command)?
+        };
+
+        writeln!(out, "state {:?}", self.node.state)?;
+        out.flush()?;
+        Ok(true)
+    }
+}
+
+pub fn serve<R: BufRead, W: Write>(listing: &Listing, budget: Option<usize>, input: R, mut out: W) -> io::Result<()> {
+    let mut session = Session::new(listing, budget);
+    if let Some(name) = &listing.header.name {
+        writeln!(out, "program {}", name)?;
+    };
+    writeln!(out, "state {:?}", session.node.state)?;
+    out.flush()?;
+
+    for line in input.lines() {
+        if !session.handle(&line?, &mut out)? {
+            break;
+        };
+    };
+
+    Ok(())
+}
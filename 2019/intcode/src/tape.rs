@@ -0,0 +1,176 @@
+use std::fs;
+use std::io;
+
+use std::fmt;
+use std::error::Error;
+
+use crate::{run, Node, Program, State, Word};
+
+
+// everything a node read and wrote, each stamped with how many instructions
+// it had executed by then. feeding the inputs back into the same starting
+// memory has to reproduce the outputs exactly, so a driver that stops working
+// can be told apart from a VM that did.
+//
+// inputs go on the tape as the program reads them, not as the driver queues
+// them: `Node.input` is the driver's to push to however it likes, and only a
+// read has a step to stamp it with. anything still queued when the node halts
+// was never used, so it's left off, and replaying it would change nothing.
+// on disk it's one entry per line:
+//
+//     patch 0 2
+//     in 1204 -1
+//     out 1230 12
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Entry<W = i64> {
+    Input { step: usize, value: W },
+    Output { step: usize, value: W }
+}
+
+impl<W: Word> fmt::Display for Entry<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Entry::Input { step, value } => write!(f, "in {} {}", step, value),
+            Entry::Output { step, value } => write!(f, "out {} {}", step, value)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Tape<W = i64> {
+    // cells the driver changed before the first run
+    pub patches: Vec<(usize, W)>,
+    pub entries: Vec<Entry<W>>
+}
+
+impl<W: Word> Tape<W> {
+    // a blank tape for a node that was loaded with `program`, noting whatever
+    // the driver has changed since
+    pub fn start(program: &[W], node: &Node<W>) -> Self {
+        let zero = W::from_i64(0);
+        let patches = (0..program.len().max(node.program.len()))
+            .filter(|&addr| program.get(addr).unwrap_or(&zero) != node.program.get(addr).unwrap_or(&zero))
+            .map(|addr| (addr, node.get(addr)))
+            .collect();
+
+        Tape { patches, entries: Vec::new() }
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), io::Error> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn inputs(&self) -> impl Iterator<Item = &W> {
+        self.entries.iter().filter_map(|entry| match entry {
+            Entry::Input { value, .. } => Some(value),
+            Entry::Output { .. } => None
+        })
+    }
+}
+
+impl<W: Word> fmt::Display for Tape<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (addr, value) in &self.patches {
+            writeln!(f, "patch {} {}", addr, value)?;
+        };
+        for entry in &self.entries {
+            writeln!(f, "{}", entry)?;
+        };
+        Ok(())
+    }
+}
+
+
+pub fn parse_tape(text: &str) -> Result<Tape, String> {
+    let mut tape = Tape::default();
+    for (i, line) in text.lines().enumerate() {
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        let numbers = |a: &str, b: &str| match (a.parse(), b.parse()) {
+            (Ok(a), Ok(b)) => Ok((a, b)),
+            _ => Err(format!("line {}: expected two numbers, found {:?}", i + 1, line))
+        };
+        match words[..] {
+            [] => (),
+            ["patch", addr, value] => tape.patches.push(numbers(addr, value)?),
+            ["in", step, value] => {
+                let (step, value) = numbers(step, value)?;
+                tape.entries.push(Entry::Input { step, value });
+            },
+            ["out", step, value] => {
+                let (step, value) = numbers(step, value)?;
+                tape.entries.push(Entry::Output { step, value });
+            },
+            _ => return Err(format!("line {}: expected patch, in or out, found {:?}", i + 1, line))
+        };
+    };
+
+    Ok(tape)
+}
+
+pub fn load_tape(path: &str) -> Result<Tape, io::Error> {
+    let contents = fs::read_to_string(path)?;
+    parse_tape(&contents).map_err(|e| io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("{}, {}", path, e)
+    ))
+}
+
+
+// the first place a replay went differently from the recording. either side
+// is None if that run had already stopped
+#[derive(Debug, PartialEq, Eq)]
+pub struct Divergence<W = i64> {
+    pub index: usize,
+    pub expected: Option<Entry<W>>,
+    pub found: Option<Entry<W>>
+}
+
+impl<W: Word> fmt::Display for Divergence<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let show = |entry: &Option<Entry<W>>| match entry {
+            Some(entry) => entry.to_string(),
+            None => "nothing more".to_string()
+        };
+        write!(f, "Replay diverged at entry {}: expected {}, found {}",
+               self.index, show(&self.expected), show(&self.found))
+    }
+}
+
+impl<W: Word> Error for Divergence<W> {}
+
+// runs `program` on the tape's patches and inputs alone, until it halts or
+// wants an input the tape doesn't have, and checks it did the same things at
+// the same steps. gives back the new recording if so
+pub fn replay<W: Word>(program: &Program<W>, tape: &Tape<W>) -> Result<Tape<W>, Divergence<W>> {
+    let mut node = Node::new('R', program);
+    for (addr, value) in &tape.patches {
+        node.set(*addr, value.clone());
+    };
+    node.tape = Some(Tape::start(program, &node));
+
+    let mut inputs = tape.inputs();
+    while node.state != State::Halted {
+        run(&mut node);
+        match node.state {
+            State::InputWait => match inputs.next() {
+                Some(input) => node.input.push_back(input.clone()),
+                None => break
+            },
+            State::OutputYield => node.output.clear(),
+            State::Halted => (),
+            _ => break
+        };
+    };
+
+    let replayed = node.tape.take().expect("Tape went missing!");
+    let length = tape.entries.len().max(replayed.entries.len());
+    match (0..length).find(|&i| tape.entries.get(i) != replayed.entries.get(i)) {
+        Some(index) => Err(Divergence {
+            index,
+            expected: tape.entries.get(index).cloned(),
+            found: replayed.entries.get(index).cloned()
+        }),
+        None => Ok(replayed)
+    }
+}
@@ -0,0 +1,65 @@
+use std::fmt;
+use std::convert::TryFrom;
+use std::hash::Hash;
+
+use num_bigint::BigInt;
+
+
+// how Add and Mul treat results that don't fit in a cell. only fixed-width
+// words can overflow, so a BigInt machine behaves the same in every mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Arithmetic {
+    Wrapping,
+    Checked
+}
+
+// a memory cell. addresses, opcodes and jump targets always have to fit in an
+// i64, whatever the cell type
+pub trait Word: Clone + Eq + Ord + Hash + fmt::Debug + fmt::Display {
+    fn from_i64(i: i64) -> Self;
+    fn to_i64(&self) -> i64;
+    fn add(&self, other: &Self, arithmetic: Arithmetic) -> Option<Self>;
+    fn mul(&self, other: &Self, arithmetic: Arithmetic) -> Option<Self>;
+}
+
+impl Word for i64 {
+    fn from_i64(i: i64) -> Self {
+        i
+    }
+
+    fn to_i64(&self) -> i64 {
+        *self
+    }
+
+    fn add(&self, other: &Self, arithmetic: Arithmetic) -> Option<Self> {
+        match arithmetic {
+            Arithmetic::Wrapping => Some(self.wrapping_add(*other)),
+            Arithmetic::Checked => self.checked_add(*other)
+        }
+    }
+
+    fn mul(&self, other: &Self, arithmetic: Arithmetic) -> Option<Self> {
+        match arithmetic {
+            Arithmetic::Wrapping => Some(self.wrapping_mul(*other)),
+            Arithmetic::Checked => self.checked_mul(*other)
+        }
+    }
+}
+
+impl Word for BigInt {
+    fn from_i64(i: i64) -> Self {
+        BigInt::from(i)
+    }
+
+    fn to_i64(&self) -> i64 {
+        i64::try_from(self).unwrap_or_else(|_| panic!("{} is too large to use as an address", self))
+    }
+
+    fn add(&self, other: &Self, _: Arithmetic) -> Option<Self> {
+        Some(self + other)
+    }
+
+    fn mul(&self, other: &Self, _: Arithmetic) -> Option<Self> {
+        Some(self * other)
+    }
+}
@@ -0,0 +1,90 @@
+use intcode::{run, Arithmetic, Fault, Node, Op, Overflow, Program, State, Word};
+use intcode::conformance::{cases, reference, Case, Outcome};
+
+use num_bigint::BigInt;
+
+
+fn puzzle(name: &str, source: &str, input: &[i64], output: &[i64]) -> Case {
+    let program = intcode::parse(source).expect("Couldn't parse puzzle input!").patched();
+    Case::outputs(name, &program, input, output)
+}
+
+#[test]
+fn every_case_passes() {
+    let failures = cases()
+        .iter()
+        .filter_map(|case| case.check(reference).err())
+        .collect::<Vec<_>>();
+
+    assert!(failures.is_empty(), "{} failing cases:\n{}", failures.len(), failures.join("\n"));
+}
+
+#[test]
+fn day_2_gravity_assist() {
+    let mut program = intcode::parse(include_str!("../../2/input.txt")).unwrap().program;
+    program[1] = 12;
+    program[2] = 2;
+    Case::leaves("day 2 part 1", &program, &[(0, 3085697)]).check(reference).unwrap();
+}
+
+#[test]
+fn day_5_diagnostics() {
+    let source = include_str!("../../5/input.txt");
+    let mut air_conditioner = vec![0; 9];
+    air_conditioner.push(5182797);
+    puzzle("day 5 part 1", source, &[1], &air_conditioner).check(reference).unwrap();
+    puzzle("day 5 part 2", source, &[5], &[12077198]).check(reference).unwrap();
+}
+
+#[test]
+fn day_9_boost_self_test() {
+    let source = include_str!("../../9/input.txt");
+    puzzle("day 9 part 1", source, &[1], &[3507134798]).check(reference).unwrap();
+    puzzle("day 9 part 2", source, &[2], &[84513]).check(reference).unwrap();
+}
+
+#[test]
+fn every_case_passes_with_big_integers() {
+    let engine = |program: &Program, input: &[i64]| {
+        let mut node = Node::new('T', &intcode::widen(program));
+        node.input.extend(input.iter().map(|&i| BigInt::from(i)));
+        while node.state != State::Halted {
+            run(&mut node);
+            if node.state == State::InputWait {
+                return Err("ran out of input".to_string());
+            };
+        };
+        Ok(Outcome {
+            output: node.output.iter().map(Word::to_i64).collect(),
+            memory: node.program.iter().map(Word::to_i64).collect()
+        })
+    };
+
+    let failures = cases()
+        .iter()
+        .filter_map(|case| case.check(engine).err())
+        .collect::<Vec<_>>();
+
+    assert!(failures.is_empty(), "{} failing cases:\n{}", failures.len(), failures.join("\n"));
+}
+
+#[test]
+fn overflow_depends_on_arithmetic() {
+    let program = vec![1102, i64::MAX, 3, 9, 1001, 9, 1, 9, 99, 0];
+
+    let mut node = Node::new('W', &program);
+    run(&mut node);
+    assert_eq!(node.state, State::Halted);
+    assert_eq!(node.get(9), i64::MAX.wrapping_mul(3).wrapping_add(1));
+
+    let mut node = Node::new('C', &program);
+    node.arithmetic = Arithmetic::Checked;
+    run(&mut node);
+    assert_eq!(node.state, State::Faulted);
+    assert_eq!(node.fault, Some(Fault::Overflow(Overflow { ip: 0, op: Op::Mul, operands: (i64::MAX, 3) })));
+
+    let mut node = Node::new('B', &intcode::widen(&program));
+    run(&mut node);
+    assert_eq!(node.state, State::Halted);
+    assert_eq!(node.get(9), BigInt::from(i64::MAX) * 3 + 1);
+}
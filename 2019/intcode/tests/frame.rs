@@ -0,0 +1,43 @@
+use intcode::{run, Framing, Node, State};
+
+
+#[derive(Debug, PartialEq, Eq)]
+enum Event {
+    Tile { x: i64, y: i64, kind: i64 },
+    Score(i64)
+}
+
+fn screen() -> Framing<Event> {
+    Framing::new(3, |frame| match *frame {
+        [-1, 0, score] => Event::Score(score),
+        [x, y, kind] => Event::Tile { x, y, kind },
+        _ => unreachable!()
+    })
+}
+
+fn events(program: &[i64]) -> Result<Vec<Event>, String> {
+    let framing = screen();
+    let mut node = Node::new('C', &program.to_vec());
+    let mut events = Vec::new();
+    while node.state != State::Halted {
+        run(&mut node);
+        events.extend(framing.take(&mut node).map_err(|e| e.to_string())?);
+    };
+    Ok(events)
+}
+
+#[test]
+fn outputs_become_events() {
+    let program = [104, 1, 104, 2, 104, 3, 104, -1, 104, 0, 104, 12345, 99];
+    assert_eq!(events(&program), Ok(vec![
+        Event::Tile { x: 1, y: 2, kind: 3 },
+        Event::Score(12345)
+    ]));
+}
+
+#[test]
+fn halting_mid_frame() {
+    let program = [104, 1, 104, 2, 104, 3, 104, 4, 104, 5, 99];
+    assert_eq!(events(&program),
+               Err("Node C halted partway through a frame, with 2 of 3 values: [4, 5]".to_string()));
+}
@@ -0,0 +1,60 @@
+use intcode::{parse, parse_setup, LoadError};
+
+
+fn error(text: &str) -> (usize, usize) {
+    let LoadError { line, column, .. } = parse(text).unwrap_err();
+    (line, column)
+}
+
+#[test]
+fn errors_say_where() {
+    assert_eq!(error("1,2,x,4"), (1, 5));
+    assert_eq!(error("1, 2\n  3 foo"), (2, 5));
+    assert_eq!(error("@patch 0=2 5=x\n1"), (1, 14));
+    assert_eq!(error("@patch 0=2 5\n1"), (1, 12));
+    assert_eq!(error("  @poke 1\n1"), (1, 3));
+    assert_eq!(error("1,2\n@input 1"), (2, 1));
+    assert_eq!(error("# nothing\n\n"), (2, 1));
+
+    let e = parse("1,2,x,4").unwrap_err();
+    assert_eq!(e.to_string(), "line 1, column 5: expected an integer, found \"x\"");
+}
+
+#[test]
+fn comments_and_blank_lines() {
+    let listing = parse("# header\n\n1, 2, # two\n\n  3 # three\n99\n").unwrap();
+    assert_eq!(listing.program, vec![1, 2, 3, 99]);
+    assert!(listing.header.name.is_none());
+}
+
+#[test]
+fn header_directives() {
+    let listing = parse("@name arcade\n@patch 0=2, 5=-1  # two of them\n@input 1, 0, -1\n1,2,3").unwrap();
+    assert_eq!(listing.header.name.as_deref(), Some("arcade"));
+    assert_eq!(listing.header.patches, vec![(0, 2), (5, -1)]);
+    assert_eq!(listing.header.inputs, vec![1, 0, -1]);
+    assert_eq!(listing.program, vec![1, 2, 3]);
+    assert_eq!(listing.patched(), vec![2, 2, 3, 0, 0, -1]);
+
+    assert_eq!(parse_setup("# just a header\n\n@input 4\n").unwrap().inputs, vec![4]);
+    let LoadError { line, column, .. } = parse_setup("@input 4\n 1,2").unwrap_err();
+    assert_eq!((line, column), (2, 2));
+}
+
+#[test]
+fn options_override_the_header() {
+    let mut listing = parse("@name arcade\n@patch 0=2\n@input 1, 2\n1,0,0,0,99").unwrap();
+    listing.header.merge(parse_setup("@patch 0=3 4=98\n@input 7").unwrap());
+    assert_eq!(listing.header.name.as_deref(), Some("arcade"));
+    assert_eq!(listing.header.inputs, vec![7]);
+
+    let node = listing.boot('A');
+    assert_eq!((node.get(0), node.get(4)), (3, 98));
+    assert_eq!(node.input, vec![7]);
+
+    // no inputs given leaves the header's be
+    let mut listing = parse("@input 1, 2\n99").unwrap();
+    listing.header.merge(parse_setup("@name other").unwrap());
+    assert_eq!(listing.header.name.as_deref(), Some("other"));
+    assert_eq!(listing.header.inputs, vec![1, 2]);
+}
@@ -0,0 +1,57 @@
+use intcode::{run, Node, State};
+
+
+fn node(program: &[i64]) -> Node {
+    let mut node = Node::new('A', &program.to_vec());
+    node.detect_loops = true;
+    node
+}
+
+#[test]
+fn spinning_in_place() {
+    // jnz 1, 0
+    let mut node = node(&[1105, 1, 0]);
+    run(&mut node);
+    assert_eq!(node.state, State::LoopDetected);
+    assert_eq!(node.steps, 2);
+}
+
+#[test]
+fn polling_for_input_is_not_a_loop() {
+    // in [10]; jnz 1, 0
+    let mut node = node(&[3, 10, 1105, 1, 0]);
+    node.input.extend(vec![1, 1, 1]);
+    run(&mut node);
+    assert_eq!(node.state, State::InputWait);
+    assert_eq!(node.steps, 6);
+
+    node.input.push_back(1);
+    run(&mut node);
+    assert_eq!(node.state, State::InputWait);
+}
+
+#[test]
+fn polling_for_output_is_not_a_loop() {
+    // out 7; jnz 1, 0
+    let mut node = node(&[104, 7, 1105, 1, 0]);
+    for _ in 0..3 {
+        run(&mut node);
+        assert_eq!(node.state, State::OutputYield);
+    };
+    assert_eq!(Vec::from(node.output), vec![7, 7, 7]);
+}
+
+#[test]
+fn running_out_of_budget() {
+    let mut node = Node::new('A', &vec![1105, 1, 0]);
+    node.budget = Some(10);
+    run(&mut node);
+    assert_eq!(node.state, State::BudgetExhausted);
+    assert_eq!((node.steps, node.budget), (10, Some(0)));
+
+    // and picks up where it left off with more
+    node.budget = Some(5);
+    run(&mut node);
+    assert_eq!(node.state, State::BudgetExhausted);
+    assert_eq!(node.steps, 15);
+}
@@ -0,0 +1,44 @@
+use std::env;
+use std::fs;
+
+use intcode::{options, take_option};
+
+
+fn args(line: &str) -> Vec<String> {
+    line.split_whitespace().map(String::from).collect()
+}
+
+#[test]
+fn later_options_win() {
+    let script = env::temp_dir().join("intcode-options-test.setup");
+    fs::write(&script, "# quarters\n@patch 0=2\n@input 9\n").unwrap();
+
+    let line = format!("day13 --input 1,2 input.txt --setup {} --patch 0=3 --input 4 -v", script.display());
+    let (rest, setup) = options(args(&line)).unwrap();
+    assert_eq!(rest, args("day13 input.txt -v"));
+    assert_eq!(setup.patches, vec![(0, 2), (0, 3)]);
+    assert_eq!(setup.inputs, vec![4]);
+
+    let mut listing = intcode::parse("@input 7\n3,0,99").unwrap();
+    listing.header.merge(setup);
+    let node = listing.boot('A');
+    assert_eq!(node.program, vec![3, 0, 99]);
+    assert_eq!(Vec::from(node.input), vec![4]);
+}
+
+#[test]
+fn bad_options() {
+    assert_eq!(options(args("day2 --patch 1:12")).unwrap_err().to_string(),
+               "--patch 1:12: expected addr=value, found \"1:12\"");
+    assert_eq!(options(args("day2 --input")).unwrap_err().to_string(), "--input needs a value");
+    assert!(intcode::parse_setup("@patch 1=2\n1,2,3").is_err());
+}
+
+#[test]
+fn taking_one_option() {
+    let mut rest = args("--record tape.txt --frames 10 --save");
+    assert_eq!(take_option(&mut rest, "--frames"), Ok(Some("10".to_string())));
+    assert_eq!(take_option(&mut rest, "--load"), Ok(None));
+    assert_eq!(take_option(&mut rest, "--save"), Err("--save needs a value".to_string()));
+    assert_eq!(rest, args("--record tape.txt --save"));
+}
@@ -0,0 +1,61 @@
+use intcode::repl::Shell;
+
+
+fn session(shell: &mut Shell, script: &str) -> String {
+    let mut replies = Vec::new();
+    for line in script.lines() {
+        shell.handle(line, &mut replies).unwrap();
+    };
+    String::from_utf8(replies).unwrap()
+}
+
+#[test]
+fn poke_step_and_disassemble() {
+    let listing = intcode::parse("3,11,102,2,11,11,4,11,1105,1,0,0").unwrap();
+    let mut shell = Shell::new(listing);
+
+    assert_eq!(session(&mut shell, "dis 0 5\npeek 8 4").lines().collect::<Vec<_>>(), vec![
+        ">      0  inp [11]",
+        "       2  mul 2, [11], [11]",
+        "       6  out [11]",
+        "       8  jnz 1, 0",
+        "      11  data 0",
+        "     8: 1105 1 0 0",
+    ]);
+
+    assert_eq!(session(&mut shell, "input 21\nstep 2\nrun\npoke 8 99\nrun\nrb 9\nrb\nbogus"), "\
+        BudgetExhausted at 6: out [11]\n\
+        output 42\n\
+        OutputYield at 8: jnz 1, 0\n\
+        Halted at 8: hlt\n\
+        9\n\
+        error unknown command bogus, see help\n");
+    assert_eq!(shell.node().program[11], 42);
+}
+
+#[test]
+fn faults_are_forgotten_on_resuming() {
+    // [9] + [10] into [11], which overflows until [10] is poked
+    let listing = intcode::parse("1,9,10,11,99,0,0,0,0,9223372036854775807,1,0").unwrap();
+    let mut shell = Shell::new(listing);
+
+    assert_eq!(session(&mut shell, "arith\narith checked\nrun\npoke 10 0\nrun\narith signed"), "\
+        Wrapping\n\
+        fault Add 9223372036854775807 1 overflowed at ip 0\n\
+        Faulted at 0: add [9], [10], [11]\n\
+        Halted at 4: hlt\n\
+        error no signed arithmetic, only wrapping or checked\n");
+    assert!(shell.node().fault.is_none());
+}
+
+#[test]
+fn unknown_opcodes_fault() {
+    let listing = intcode::parse("1101,20,22,0,99").unwrap();
+    let mut shell = Shell::new(listing);
+
+    assert_eq!(session(&mut shell, "run\nreset\npoke 4 42\nrun\npoke 4 99\nrun"), "\
+        Halted at 4: hlt\n\
+        fault unknown opcode 42 at ip 4\n\
+        Faulted at 4: data 42\n\
+        Halted at 4: hlt\n");
+}
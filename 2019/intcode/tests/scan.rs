@@ -0,0 +1,49 @@
+use intcode::{run, Node, State};
+use intcode::scan::{Filter, Freezer, Scanner};
+
+
+// counts up in cell 13 and echoes each input, so there's one cell that
+// changes between inputs and one that follows the input
+//     loop: inp [12]; add [13], 1, [13]; out [12]; jnz 1, loop
+const COUNTER: [i64; 14] = [3, 12, 1001, 13, 1, 13, 4, 12, 1105, 1, 0, 99, 0, 0];
+
+fn feed(node: &mut Node, value: i64) {
+    node.input.push_back(value);
+    run(node);
+    assert_eq!(node.state, State::OutputYield);
+    node.output.clear();
+}
+
+#[test]
+fn narrows_to_candidates() {
+    let mut node = Node::new('S', &COUNTER.to_vec());
+    let mut scanner = Scanner::new(&node);
+
+    feed(&mut node, 7);
+    assert_eq!(scanner.filter(&node, &Filter::Changed), &[12, 13]);
+    feed(&mut node, 7);
+    assert_eq!(scanner.filter(&node, &Filter::Increased), &[13]);
+
+    let mut scanner = Scanner::new(&node);
+    feed(&mut node, 5);
+    scanner.filter(&node, &Filter::Decreased);
+    assert_eq!(scanner.filter(&node, &Filter::Equal(5)), &[12]);
+    assert_eq!(scanner.candidates(), &[12]);
+}
+
+#[test]
+fn frozen_cells_stay_put() {
+    let mut node = Node::new('S', &COUNTER.to_vec());
+    let mut freezer = Freezer::new();
+    freezer.freeze(13, 40);
+    for _ in 0..3 {
+        freezer.apply(&mut node);
+        feed(&mut node, 1);
+    };
+    assert_eq!(node.get(13), 41);
+
+    freezer.thaw(13);
+    freezer.apply(&mut node);
+    feed(&mut node, 1);
+    assert_eq!(node.get(13), 42);
+}
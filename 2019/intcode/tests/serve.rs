@@ -0,0 +1,40 @@
+use std::io::Cursor;
+
+use intcode::serve::serve;
+
+
+#[test]
+fn protocol_round_trip() {
+    let listing = intcode::parse("@name doubler\n3,11,102,2,11,11,4,11,1105,1,0,0").unwrap();
+    let script = "run\ninput 4 5\nsnapshot\nbogus\nreset\nquit\nrun\n";
+    let mut replies = Vec::new();
+    serve(&listing, Some(1000), Cursor::new(script), &mut replies).unwrap();
+
+    assert_eq!(String::from_utf8(replies).unwrap(), "\
+        program doubler\n\
+        state Boot\n\
+        state InputWait\n\
+        output 8\n\
+        output 10\n\
+        state InputWait\n\
+        snapshot ip 0 rb 0 memory 3,11,102,2,11,11,4,11,1105,1,0,10\n\
+        state InputWait\n\
+        error unknown command bogus\n\
+        state InputWait\n\
+        state Boot\n");
+}
+
+#[test]
+fn unknown_opcodes_are_errors() {
+    let listing = intcode::parse("104,7,42").unwrap();
+    let mut replies = Vec::new();
+    serve(&listing, None, Cursor::new("run\nrun\n"), &mut replies).unwrap();
+
+    assert_eq!(String::from_utf8(replies).unwrap(), "\
+        state Boot\n\
+        output 7\n\
+        error unknown opcode 42 at ip 2\n\
+        state Faulted\n\
+        error unknown opcode 42 at ip 2\n\
+        state Faulted\n");
+}
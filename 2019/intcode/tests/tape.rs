@@ -0,0 +1,65 @@
+use intcode::{parse_tape, replay, run, Divergence, Entry, Node, State, Tape};
+
+
+// doubles whatever it reads, forever
+const DOUBLER: [i64; 12] = [3, 11, 102, 2, 11, 11, 4, 11, 1105, 1, 0, 0];
+
+fn record(inputs: &[i64]) -> Tape {
+    let program = DOUBLER.to_vec();
+    let mut node = Node::new('D', &program);
+    node.set(11, 99);
+    node.tape = Some(Tape::start(&program, &node));
+
+    let mut inputs = inputs.iter();
+    while node.state != State::Halted {
+        run(&mut node);
+        if node.state == State::InputWait {
+            match inputs.next() {
+                Some(&input) => node.input.push_back(input),
+                None => break
+            };
+        };
+    };
+    node.tape.take().unwrap()
+}
+
+#[test]
+fn records_and_replays() {
+    let tape = record(&[4, -5]);
+    assert_eq!(tape.to_string(), "patch 11 99\nin 1 4\nout 3 8\nin 5 -5\nout 7 -10\n");
+    assert_eq!(parse_tape(&tape.to_string()), Ok(tape.clone()));
+    assert_eq!(replay(&DOUBLER.to_vec(), &tape), Ok(tape));
+}
+
+#[test]
+fn reports_divergence() {
+    let mut tape = record(&[4, -5]);
+    tape.entries[3] = Entry::Output { step: 7, value: 10 };
+    assert_eq!(replay(&DOUBLER.to_vec(), &tape), Err(Divergence {
+        index: 3,
+        expected: Some(Entry::Output { step: 7, value: 10 }),
+        found: Some(Entry::Output { step: 7, value: -10 })
+    }));
+
+    let mut tape = record(&[4]);
+    tape.entries.push(Entry::Output { step: 7, value: 0 });
+    assert_eq!(replay(&DOUBLER.to_vec(), &tape).unwrap_err().to_string(),
+               "Replay diverged at entry 2: expected out 7 0, found nothing more");
+}
+
+#[test]
+fn unread_inputs_are_left_off() {
+    // reads one input, doubles it and halts
+    let program = vec![3, 9, 102, 2, 9, 9, 4, 9, 99, 0];
+    let mut node = Node::new('D', &program);
+    node.tape = Some(Tape::start(&program, &node));
+    node.input.extend(vec![4, 5, 6]);
+    while node.state != State::Halted {
+        run(&mut node);
+    };
+    assert_eq!(Vec::from(node.input), vec![5, 6]);
+
+    let tape = node.tape.take().unwrap();
+    assert_eq!(tape.to_string(), "in 1 4\nout 3 8\n");
+    assert_eq!(replay(&program, &tape), Ok(tape));
+}
@@ -0,0 +1,111 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+
+// writes a test for each part of each sample input a day keeps next to its
+// solution, for tests/samples.rs to pick up. samples are named sample*.txt or
+// *-test.txt, and the day's expected.txt has what they should come to:
+//
+//     <sample file> <part> <answer> [# <why it's not right yet>]
+//
+// parts missing from there get a test that's ignored, so they still show up.
+// so do answers with a reason after them, which the day is known to get wrong
+
+fn is_sample(name: &str) -> bool {
+    name.ends_with(".txt") && (name.starts_with("sample") || name.ends_with("-test.txt"))
+}
+
+// the answer, and the reason it's known to be wrong, if it is
+struct Expected {
+    answer: String,
+    wrong: Option<String>
+}
+
+fn expected(path: &Path) -> BTreeMap<(String, u8), Expected> {
+    let mut expected = BTreeMap::new();
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(_) => return expected
+    };
+
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        };
+        let fields = line.splitn(3, ' ').collect::<Vec<_>>();
+        let part = match fields.as_slice() {
+            [_, part, _] => part.parse().ok().filter(|part| *part == 1 || *part == 2),
+            _ => None
+        };
+        let part = part.unwrap_or_else(|| panic!("{}:{}: expected <sample file> <part> <answer>", path.display(), i + 1));
+        let (answer, wrong) = match fields[2].split_once(" # ") {
+            Some((answer, why)) => (answer.trim().to_string(), Some(why.trim().to_string())),
+            None => (fields[2].to_string(), None)
+        };
+        expected.insert((fields[0].to_string(), part), Expected { answer, wrong });
+    };
+    expected
+}
+
+fn numbered(dir: &Path) -> Vec<(u32, String)> {
+    let mut entries = fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("Couldn't read {}: {}", dir.display(), e))
+        .map(|entry| entry.expect("Couldn't read directory entry!").file_name().to_string_lossy().into_owned())
+        .filter_map(|name| name.parse().ok().map(|n| (n, name)))
+        .collect::<Vec<_>>();
+    entries.sort();
+    entries
+}
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().expect("No repo above the aoc crate!");
+    let mut tests = String::new();
+
+    // not the top of the repo, which has target/ in it. a new year gets
+    // registered in lib.rs, so that'll do to notice one
+    println!("cargo:rerun-if-changed=src/lib.rs");
+    for (year, year_name) in numbered(root) {
+        println!("cargo:rerun-if-changed={}", root.join(&year_name).display());
+        for (day, day_name) in numbered(&root.join(&year_name)) {
+            let dir = root.join(&year_name).join(&day_name);
+            println!("cargo:rerun-if-changed={}", dir.display());
+
+            let mut samples = fs::read_dir(&dir)
+                .unwrap_or_else(|e| panic!("Couldn't read {}: {}", dir.display(), e))
+                .map(|entry| entry.expect("Couldn't read directory entry!").file_name().to_string_lossy().into_owned())
+                .filter(|name| is_sample(name))
+                .collect::<Vec<_>>();
+            samples.sort();
+
+            let expected_path = dir.join("expected.txt");
+            let expected = expected(&expected_path);
+            for (sample, _) in expected.keys() {
+                assert!(samples.contains(sample), "{} has answers for {}, which isn't a sample", expected_path.display(), sample);
+            };
+
+            for sample in &samples {
+                let stem = sample.trim_end_matches(".txt").replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+                for part in 1..=2 {
+                    let name = format!("y{}_day{}_{}_part{}", year, day, stem, part);
+                    match expected.get(&(sample.clone(), part)) {
+                        Some(Expected { answer, wrong }) => tests.push_str(&format!(
+                            "#[test]\n{}fn {}() {{\n    check({}, {}, {:?}, {}, {:?});\n}}\n\n",
+                            wrong.as_ref().map_or_else(String::new, |why| format!("#[ignore = {:?}]\n", why)),
+                            name, year, day, sample, part, answer
+                        )),
+                        None => tests.push_str(&format!(
+                            "#[test]\n#[ignore = \"no answer in {}/{}/expected.txt\"]\nfn {}() {{\n    unchecked({}, {}, {:?}, {});\n}}\n\n",
+                            year, day, name, year, day, sample, part
+                        ))
+                    };
+                };
+            };
+        };
+    };
+
+    let out = Path::new(&env::var("OUT_DIR").expect("No OUT_DIR!")).join("samples.rs");
+    fs::write(&out, tests).unwrap_or_else(|e| panic!("Couldn't write {}: {}", out.display(), e));
+}
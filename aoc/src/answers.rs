@@ -0,0 +1,88 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+
+// known-good answers, one per line:
+//
+//     <year> <day> <input file> <part> <answer>
+//
+// the input file is named relative to the day's directory, and the answer is
+// the rest of the line. blank lines and lines starting with # are skipped
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    entries: BTreeMap<(u32, u32, String, u8), String>
+}
+
+fn invalid(line: usize, message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("line {}: {}", line, message))
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self, io::Error> {
+        let mut entries = BTreeMap::new();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            };
+
+            let fields = line.splitn(5, ' ').collect::<Vec<_>>();
+            if fields.len() < 5 {
+                return Err(invalid(i + 1, "expected <year> <day> <input file> <part> <answer>"));
+            };
+            let number = |field: &str| field.parse().map_err(|_| invalid(i + 1, &format!("bad number {:?}", field)));
+            let (year, day, part) = (number(fields[0])?, number(fields[1])?, number(fields[3])?);
+            if part != 1 && part != 2 {
+                return Err(invalid(i + 1, &format!("no part {}", part)));
+            };
+            entries.insert((year, day, fields[2].to_string(), part as u8), fields[4].to_string());
+        };
+
+        Ok(Answers { entries })
+    }
+
+    // a missing file is just an empty store
+    pub fn load(path: &Path) -> Result<Self, io::Error> {
+        match fs::read_to_string(path) {
+            Ok(text) => Answers::parse(&text),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e)
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), io::Error> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, year: u32, day: u32, input: &str, part: u8) -> Option<&str> {
+        self.entries.get(&(year, day, input.to_string(), part)).map(String::as_str)
+    }
+
+    pub fn set(&mut self, year: u32, day: u32, input: &str, part: u8, answer: &str) {
+        self.entries.insert((year, day, input.to_string(), part), answer.to_string());
+    }
+
+    // every input file with an answer recorded for the day
+    pub fn inputs(&self, year: u32, day: u32) -> Vec<&str> {
+        let mut inputs = self.entries
+            .keys()
+            .filter(|(y, d, _, _)| *y == year && *d == day)
+            .map(|(_, _, input, _)| input.as_str())
+            .collect::<Vec<_>>();
+        inputs.dedup();
+        inputs
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# year day input part answer")?;
+        for ((year, day, input, part), answer) in &self.entries {
+            writeln!(f, "{} {} {} {} {}", year, day, input, part, answer)?;
+        };
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+use std::fs;
+use std::io;
+use std::panic;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::report;
+use crate::verify::format_time;
+use crate::Day;
+
+
+// how long one phase took over all the runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub median_ns: u64,
+    // between the fastest and slowest run
+    pub spread_ns: u64
+}
+
+impl Stats {
+    pub fn of(times: &[Duration]) -> Self {
+        assert!(!times.is_empty(), "No times!");
+        let mut nanos = times.iter().map(|time| time.as_nanos() as u64).collect::<Vec<_>>();
+        nanos.sort_unstable();
+
+        let middle = nanos.len() / 2;
+        let median_ns = if nanos.len() % 2 == 0 {
+            (nanos[middle - 1] + nanos[middle]) / 2
+        } else {
+            nanos[middle]
+        };
+        Stats { median_ns, spread_ns: nanos[nanos.len() - 1] - nanos[0] }
+    }
+
+    // slower than `threshold` percent over before, by more than it used to
+    // vary between runs
+    pub fn regressed(&self, before: &Stats, threshold: f64) -> bool {
+        let slower = self.median_ns.saturating_sub(before.median_ns);
+        self.median_ns as f64 > before.median_ns as f64 * (1.0 + threshold / 100.0) && slower > before.spread_ns
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bench {
+    pub year: u32,
+    pub day: u32,
+    pub input: String,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats
+}
+
+impl Bench {
+    pub fn phases(&self) -> [(&'static str, &Stats); 3] {
+        [("parse", &self.parse), ("part 1", &self.part1), ("part 2", &self.part2)]
+    }
+}
+
+// one go of `aoc bench`, as kept in the history
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Run {
+    // seconds since the epoch
+    pub timestamp: u64,
+    pub runs: usize,
+    pub benches: Vec<Bench>
+}
+
+impl Run {
+    pub fn new(runs: usize, benches: Vec<Bench>) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Clock is before the epoch!")
+            .as_secs();
+        Run { timestamp, runs, benches }
+    }
+}
+
+// a missing history is just an empty one
+pub fn load_history(path: &Path) -> Result<Vec<Run>, io::Error> {
+    match fs::read_to_string(path) {
+        Ok(text) => serde_json::from_str(&text).map_err(|e| io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {}", path.display(), e)
+        )),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e)
+    }
+}
+
+pub fn save_history(path: &Path, history: &[Run]) -> Result<(), io::Error> {
+    let text = serde_json::to_string_pretty(history).map_err(io::Error::other)?;
+    fs::write(path, text + "\n")
+}
+
+// the last time the day was benchmarked on the same input
+pub fn previous<'a>(history: &'a [Run], bench: &Bench) -> Option<&'a Bench> {
+    history
+        .iter()
+        .rev()
+        .flat_map(|run| &run.benches)
+        .find(|b| b.year == bench.year && b.day == bench.day && b.input == bench.input)
+}
+
+// solves the day `runs` times over, without drawing anything
+pub fn bench(day: &Day, input: &str, contents: &str, runs: usize) -> Result<Bench, String> {
+    let mut timings = Vec::new();
+    for _ in 0..runs {
+        let solved = panic::catch_unwind(|| (day.solve)(contents, Vec::new()))
+            .map_err(|payload| report::panic_message(payload.as_ref()))?
+            .map_err(|e| e.to_string())?;
+        timings.push(solved.timings);
+    };
+
+    let stats = |phase: fn(&crate::Timings) -> Duration| Stats::of(&timings.iter().map(phase).collect::<Vec<_>>());
+    Ok(Bench {
+        year: day.year,
+        day: day.day,
+        input: input.to_string(),
+        parse: stats(|t| t.parse),
+        part1: stats(|t| t.part1),
+        part2: stats(|t| t.part2)
+    })
+}
+
+fn nanos(ns: u64) -> String {
+    format_time(Duration::from_nanos(ns))
+}
+
+// the benchmarks next to how they did last time, and how many got slower
+pub fn table(benches: &[Bench], history: &[Run], threshold: f64) -> (String, usize) {
+    let mut rows = vec![
+        ["year", "day", "input", "phase", "median", "spread", "before", "change", ""]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>()
+    ];
+    let mut regressions = 0;
+    for bench in benches {
+        let before = previous(history, bench);
+        let before_phases = before.map(Bench::phases);
+        for (i, (phase, stats)) in bench.phases().iter().enumerate() {
+            let before = before_phases.as_ref().map(|phases| phases[i].1);
+            let regressed = before.is_some_and(|before| stats.regressed(before, threshold));
+            if regressed {
+                regressions += 1;
+            };
+            rows.push(vec![
+                bench.year.to_string(),
+                bench.day.to_string(),
+                bench.input.clone(),
+                phase.to_string(),
+                nanos(stats.median_ns),
+                nanos(stats.spread_ns),
+                before.map_or("-".to_string(), |before| nanos(before.median_ns)),
+                match before {
+                    Some(before) if before.median_ns > 0 => format!(
+                        "{:+.0}%",
+                        (stats.median_ns as f64 / before.median_ns as f64 - 1.0) * 100.0
+                    ),
+                    _ => "-".to_string()
+                },
+                if regressed { "SLOWER".to_string() } else { String::new() }
+            ]);
+        };
+    };
+
+    (crate::verify::columns(&rows), regressions)
+}
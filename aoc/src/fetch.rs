@@ -0,0 +1,183 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+
+// what's needed to talk to the puzzle server, from a file of `key = value`
+// lines:
+//
+//     session = <the session cookie from a logged in browser>
+//     base_url = https://adventofcode.com
+//     interval = 5
+//
+// everything but the session has a default. the interval is the least number
+// of seconds between requests
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+    pub interval: Duration,
+    pub user_agent: String
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            session: None,
+            base_url: "https://adventofcode.com".to_string(),
+            interval: Duration::from_secs(5),
+            user_agent: format!("aoc runner {}", env!("CARGO_PKG_VERSION"))
+        }
+    }
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+impl Config {
+    pub fn parse(text: &str) -> Result<Self, io::Error> {
+        let mut config = Config::default();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            };
+
+            let (key, value) = line
+                .split_once('=')
+                .map(|(key, value)| (key.trim(), value.trim().to_string()))
+                .ok_or_else(|| invalid(format!("line {}: expected <key> = <value>", i + 1)))?;
+            match key {
+                "session" => config.session = Some(value),
+                "base_url" => config.base_url = value,
+                // negative, infinite or NaN seconds aren't a wait
+                "interval" => config.interval = value
+                    .parse()
+                    .ok()
+                    .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+                    .ok_or_else(|| invalid(format!("line {}: bad interval {}", i + 1, value)))?,
+                "user_agent" => config.user_agent = value,
+                _ => return Err(invalid(format!("line {}: unknown setting {}", i + 1, key)))
+            };
+        };
+        Ok(config)
+    }
+
+    // a missing file is just the defaults, with no session
+    pub fn load(path: &Path) -> Result<Self, io::Error> {
+        match fs::read_to_string(path) {
+            Ok(text) => Config::parse(&text).map_err(|e| invalid(format!("{}: {}", path.display(), e))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(e)
+        }
+    }
+}
+
+// aoc.conf at the top of the repo, unless AOC_CONFIG says otherwise. it's
+// ignored by git, since the session is as good as a password
+pub fn config_path() -> PathBuf {
+    env::var_os("AOC_CONFIG").map_or_else(|| crate::root().join("aoc.conf"), PathBuf::from)
+}
+
+// inputs are cached where the days read them from, <cache>/<year>/<day>/input.txt,
+// and once one's there it's never fetched again
+pub struct Fetcher {
+    config: Config,
+    cache: PathBuf,
+    agent: ureq::Agent
+}
+
+impl Fetcher {
+    pub fn new(config: Config, cache: &Path) -> Self {
+        Fetcher { agent: agent(&config), config, cache: cache.to_path_buf() }
+    }
+
+    pub fn cached_path(&self, year: u32, day: u32) -> PathBuf {
+        self.cache.join(year.to_string()).join(day.to_string()).join("input.txt")
+    }
+
+    pub fn is_cached(&self, year: u32, day: u32) -> bool {
+        self.cached_path(year, day).exists()
+    }
+
+    pub fn input(&self, year: u32, day: u32) -> Result<String, io::Error> {
+        let path = self.cached_path(year, day);
+        match fs::read_to_string(&path) {
+            Ok(input) => return Ok(input),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => (),
+            Err(e) => return Err(e)
+        };
+
+        let input = self.fetch(year, day)?;
+        fs::create_dir_all(path.parent().expect("Cached input has no directory!"))?;
+        fs::write(&path, &input)?;
+        Ok(input)
+    }
+
+    fn fetch(&self, year: u32, day: u32) -> Result<String, io::Error> {
+        let url = format!("{}/{}/day/{}/input", self.config.base_url.trim_end_matches('/'), year, day);
+        let session = self.config.session.as_ref().ok_or_else(|| io::Error::new(
+            io::ErrorKind::NotFound,
+            format!(
+                "No {}, and no session in {} to fetch it from {} with",
+                self.cached_path(year, day).display(), config_path().display(), url
+            )
+        ))?;
+
+        throttle(&self.cache, self.config.interval)?;
+        let response = self.agent
+            .get(&url)
+            .header("Cookie", format!("session={}", session))
+            .call();
+        let input = body(&url, response)?;
+        if input.is_empty() {
+            return Err(io::Error::other(format!("Fetching {} got nothing", url)));
+        };
+        Ok(input)
+    }
+}
+
+pub(crate) fn agent(config: &Config) -> ureq::Agent {
+    ureq::Agent::new_with_config(
+        ureq::Agent::config_builder()
+            .user_agent(config.user_agent.as_str())
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+    )
+}
+
+pub(crate) fn body(url: &str, response: Result<ureq::http::Response<ureq::Body>, ureq::Error>) -> Result<String, io::Error> {
+    match response {
+        Ok(mut response) => response.body_mut().read_to_string().map_err(io::Error::other),
+        Err(ureq::Error::StatusCode(status)) => {
+            let why = match status {
+                400 | 401 => " (has the session expired?)",
+                404 => " (is the puzzle out yet?)",
+                _ => ""
+            };
+            Err(io::Error::other(format!("{} got status {}{}", url, status, why)))
+        },
+        Err(e) => Err(io::Error::other(format!("Couldn't reach {}: {}", url, e)))
+    }
+}
+
+// waits out the interval since the last request to the server. when that was
+// is kept in the cache, so separate runs keep to it too
+pub(crate) fn throttle(cache: &Path, interval: Duration) -> Result<(), io::Error> {
+    let stamp = cache.join(".last-fetch");
+    let now = || SystemTime::now().duration_since(UNIX_EPOCH).expect("Clock is before the epoch!");
+
+    if let Some(last) = fs::read_to_string(&stamp).ok().and_then(|text| text.trim().parse().ok()) {
+        let since = now().saturating_sub(Duration::from_millis(last));
+        if since < interval {
+            thread::sleep(interval - since);
+        };
+    };
+
+    fs::create_dir_all(cache)?;
+    fs::write(&stamp, now().as_millis().to_string())
+}
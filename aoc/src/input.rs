@@ -0,0 +1,64 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+use intcode::take_option;
+
+use crate::fetch::{self, Fetcher};
+use crate::Day;
+
+
+// where a day's puzzle input comes from. every day takes its input as text,
+// so they can all be run the same way on any of these
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    // the day's input.txt, fetched into place the first time if need be
+    Default,
+    File(PathBuf),
+    Stdin,
+    Inline(String)
+}
+
+impl Source {
+    // takes `--input <file>`, where `-` is stdin, or `--inline <text>` out of
+    // the arguments
+    pub fn take(args: &mut Vec<String>) -> Result<Self, String> {
+        let file = take_option(args, "--input")?;
+        let inline = take_option(args, "--inline")?;
+        match (file, inline) {
+            (Some(_), Some(_)) => Err("Only one of --input and --inline".to_string()),
+            (Some(file), None) if file == "-" => Ok(Source::Stdin),
+            (Some(file), None) => Ok(Source::File(file.into())),
+            (None, Some(text)) => Ok(Source::Inline(text)),
+            (None, None) => Ok(Source::Default)
+        }
+    }
+
+    pub fn read(&self, day: &Day) -> Result<String, io::Error> {
+        let read = match self {
+            Source::Default => {
+                let fetcher = Fetcher::new(fetch::Config::load(&fetch::config_path())?, crate::root());
+                return fetcher.input(day.year, day.day);
+            },
+            Source::File(path) => fs::read_to_string(path),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            },
+            Source::Inline(text) => Ok(text.clone())
+        };
+        read.map_err(|e| io::Error::new(e.kind(), format!("Couldn't read {}: {}", self, e)))
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Default => write!(f, "the day's input"),
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "stdin"),
+            Source::Inline(_) => write!(f, "the inline input")
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use std::cell::RefCell;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+pub mod y2017;
+pub mod y2018;
+pub mod y2019;
+mod answers;
+pub mod bench;
+pub mod fetch;
+pub mod input;
+pub mod report;
+pub mod scaffold;
+mod solution;
+pub mod submit;
+pub mod verify;
+pub use answers::Answers;
+pub use solution::{reject_options, solve, solve_part, Answer, Solution, Solved, Timings};
+
+
+// a day's `solve`: the puzzle input, and whatever options were left over for
+// the day itself
+pub type Solve = fn(&str, Vec<String>) -> Result<Solved, Box<dyn Error>>;
+pub type SolvePart = fn(&str, Vec<String>, u8) -> Result<Answer, Box<dyn Error>>;
+
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    pub solve: Solve,
+    pub solve_part: SolvePart
+}
+
+impl Day {
+    // where the solution and its inputs live
+    pub fn dir(&self) -> PathBuf {
+        root().join(self.year.to_string()).join(self.day.to_string())
+    }
+
+    pub fn input_path(&self) -> PathBuf {
+        self.dir().join("input.txt")
+    }
+}
+
+// the top of the repo
+pub fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("No repo above the aoc crate!")
+}
+
+// whether days that draw as they go should; `verify` turns it off so they
+// don't slow down or scribble over its table
+static ANIMATE: AtomicBool = AtomicBool::new(true);
+
+pub fn animate() -> bool {
+    ANIMATE.load(Ordering::SeqCst)
+}
+
+pub fn set_animate(on: bool) {
+    ANIMATE.store(on, Ordering::SeqCst);
+}
+
+// what a day has to say besides its answers, like a picture it couldn't read.
+// it's printed straight away, unless it's being collected to go out with the
+// answers instead
+static COLLECT_DETAILS: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static DETAILS: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+pub fn detail(text: &str) {
+    if COLLECT_DETAILS.load(Ordering::SeqCst) {
+        DETAILS.with(|details| details.borrow_mut().push_str(text));
+    } else {
+        print!("{}", text);
+    };
+}
+
+pub fn set_collect_details(on: bool) {
+    COLLECT_DETAILS.store(on, Ordering::SeqCst);
+}
+
+// everything collected on this thread since last time
+pub(crate) fn take_details() -> String {
+    DETAILS.with(|details| details.replace(String::new()))
+}
+
+macro_rules! days {
+    ($($year:literal $day:literal => $solution:path),* $(,)?) => {
+        pub const DAYS: &[Day] = &[
+            $(Day { year: $year, day: $day, solve: solve::<$solution>, solve_part: solve_part::<$solution> }),*
+        ];
+    };
+}
+
+days! {
+    2017 1 => y2017::day1::Day1,
+    2017 2 => y2017::day2::Day2,
+
+    2018 1 => y2018::day1::Day1,
+    2018 2 => y2018::day2::Day2,
+    2018 3 => y2018::day3::Day3,
+    2018 4 => y2018::day4::Day4,
+    2018 5 => y2018::day5::Day5,
+    2018 6 => y2018::day6::Day6,
+    2018 7 => y2018::day7::Day7,
+    2018 8 => y2018::day8::Day8,
+    2018 9 => y2018::day9::Day9,
+    2018 10 => y2018::day10::Day10,
+    2018 11 => y2018::day11::Day11,
+    2018 12 => y2018::day12::Day12,
+    2018 13 => y2018::day13::Day13,
+
+    2019 2 => y2019::day2::Day2,
+    2019 3 => y2019::day3::Day3,
+    2019 4 => y2019::day4::Day4,
+    2019 5 => y2019::day5::Day5,
+    2019 6 => y2019::day6::Day6,
+    2019 7 => y2019::day7::Day7,
+    2019 8 => y2019::day8::Day8,
+    2019 9 => y2019::day9::Day9,
+    2019 10 => y2019::day10::Day10,
+    2019 11 => y2019::day11::Day11,
+    2019 12 => y2019::day12::Day12,
+    2019 13 => y2019::day13::Day13,
+    2019 14 => y2019::day14::Day14,
+    2019 15 => y2019::day15::Day15,
+}
+
+pub fn find(year: u32, day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
+
+pub fn year(year: u32) -> impl Iterator<Item = &'static Day> {
+    DAYS.iter().filter(move |d| d.year == year)
+}
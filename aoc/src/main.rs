@@ -0,0 +1,370 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::panic;
+use std::process;
+
+use aoc::bench::{self, Run};
+use aoc::fetch::{self, Fetcher};
+use aoc::input::Source;
+use aoc::report::{self, Record};
+use aoc::scaffold;
+use aoc::submit::{self, Reply, Submitter, Verdict};
+use aoc::verify::{self, Outcome};
+use aoc::{Answer, Answers, Day, Solved};
+
+
+const USAGE: &str = "\
+usage: aoc run <year> <day> [--input <file, or - for stdin> | --inline <input>] [--format <text|json>] [-- <options for the day>]
+       aoc run [<year>] --all [--format <text|json>]
+       aoc new <year> <day>
+       aoc fetch <year> [<day>]
+       aoc submit <year> <day> <part> [-- <options for the day>]
+       aoc verify [<year> [<day> [--input <file in the day's directory>]]] [--update]
+       aoc bench [<year> [<day> [--input <file in the day's directory>]]] [--runs <n>] [--threshold <percent>]";
+
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
+
+// how `run` reports the answers: for people, or as a JSON array of records
+// with one for each part, for anything else
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json
+}
+
+impl Format {
+    // nothing gets drawn for JSON, and what the days have to say goes in the
+    // records rather than ahead of them
+    fn set_up(self) {
+        if self == Format::Json {
+            aoc::set_animate(false);
+            aoc::set_collect_details(true);
+        };
+    }
+}
+
+fn solve(day: &Day, source: &Source, options: Vec<String>) -> Result<Solved, String> {
+    let contents = source.read(day).map_err(|e| e.to_string())?;
+    (day.solve)(&contents, options).map_err(|e| e.to_string())
+}
+
+fn print(solved: &Solved) {
+    println!("Part 1: {}", solved.part1);
+    println!("Part 2: {}", solved.part2);
+}
+
+// the error's still returned with JSON, after its records, so the exit code
+// says the same either way. a panic is an error like any other
+fn run_one(day: &Day, source: &Source, options: Vec<String>, format: Format) -> io::Result<()> {
+    format.set_up();
+    let solved = panic::catch_unwind(|| solve(day, source, options))
+        .unwrap_or_else(|payload| Err(report::panic_message(payload.as_ref())));
+    match format {
+        Format::Text => print(&solved.map_err(io::Error::other)?),
+        Format::Json => {
+            let error = solved.as_ref().err().cloned();
+            println!("{}", report::to_json(&report::records(day, solved)));
+            if let Some(error) = error {
+                return Err(io::Error::other(error));
+            };
+        }
+    };
+    Ok(())
+}
+
+// keeps going past days that fail, or panic, and says which ones did at the end
+fn run_all(days: Vec<&Day>, format: Format) -> io::Result<()> {
+    format.set_up();
+    let mut records: Vec<Record> = Vec::new();
+    let mut failed = Vec::new();
+    for day in days {
+        if format == Format::Text {
+            println!("=== {} day {}", day.year, day.day);
+        };
+        let solved = panic::catch_unwind(|| solve(day, &Source::Default, Vec::new()))
+            .unwrap_or_else(|payload| Err(report::panic_message(payload.as_ref())));
+        match (&solved, format) {
+            (Ok(solved), Format::Text) => print(solved),
+            (Err(e), Format::Text) => eprintln!("{}", e),
+            (_, Format::Json) => ()
+        };
+        if solved.is_err() {
+            failed.push(format!("{} day {}", day.year, day.day));
+        };
+        if format == Format::Json {
+            records.extend(report::records(day, solved));
+        };
+    };
+    if format == Format::Json {
+        println!("{}", report::to_json(&records));
+    };
+
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(io::Error::other(format!("{} failed", failed.join(", "))))
+    }
+}
+
+// checks the chosen days against the answers on record. with `update`,
+// whatever differs or is missing is recorded instead of failing
+fn run_verify(days: Vec<&Day>, input: Option<&str>, update: bool) -> io::Result<()> {
+    aoc::set_animate(false);
+    let path = aoc::root().join("answers.txt");
+    let mut answers = Answers::load(&path)?;
+
+    let mut checks = Vec::new();
+    for day in days {
+        let inputs = match input {
+            Some(input) => vec![input.to_string()],
+            None => verify::inputs(day, &answers)
+        };
+        if inputs.is_empty() {
+            checks.extend(verify::no_inputs(day));
+        };
+        for input in inputs {
+            checks.extend(verify::check(day, &input, &answers));
+        };
+    };
+    print!("{}", verify::table(&checks));
+
+    if update {
+        let updated = verify::update(&mut answers, &checks);
+        answers.save(&path)?;
+        println!("{} answers recorded in {}", updated, path.display());
+    };
+
+    let count = |outcome| checks.iter().filter(|check| check.outcome == outcome).count();
+    println!(
+        "{} passed, {} failed, {} missing, {} unsolved, {} errors",
+        count(Outcome::Pass), count(Outcome::Fail), count(Outcome::Missing), count(Outcome::Unsolved), count(Outcome::Error)
+    );
+    if count(Outcome::Error) > 0 || (!update && count(Outcome::Fail) > 0) {
+        Err(io::Error::other("Verification failed"))
+    } else {
+        Ok(())
+    }
+}
+
+// fetches inputs for the chosen days, skipping the ones already there
+fn run_fetch(year: u32, days: Vec<u32>) -> io::Result<()> {
+    let fetcher = Fetcher::new(fetch::Config::load(&fetch::config_path())?, aoc::root());
+    for day in days {
+        let cached = fetcher.is_cached(year, day);
+        fetcher.input(year, day)?;
+        println!(
+            "{} day {}: {} {}",
+            year, day, if cached { "already in" } else { "fetched to" }, fetcher.cached_path(year, day).display()
+        );
+    };
+    Ok(())
+}
+
+// solves the part on the day's own input, fetching it if need be, and sends
+// the answer off. a right answer is recorded for `verify` too; anything else
+// is an error, so scripts can tell
+fn run_submit(day: &Day, part: u8, options: Vec<String>) -> io::Result<()> {
+    let contents = Source::Default.read(day)?;
+    let answer = (day.solve_part)(&contents, options, part).map_err(|e| io::Error::other(e.to_string()))?;
+    if answer == Answer::Unsolved {
+        return Err(io::Error::other(format!("{} day {} part {} isn't solved", day.year, day.day, part)));
+    };
+    let answer = answer.to_string();
+    println!("Part {}: {}", part, answer);
+
+    let log_path = submit::log_path();
+    let mut log = submit::Log::load(&log_path)?;
+    let config = fetch::Config::load(&fetch::config_path())?;
+    let reply = Submitter::new(config, aoc::root()).submit(&mut log, day.year, day.day, part, &answer);
+    log.save(&log_path)?;
+    let reply = reply?;
+    if reply != Reply::Verdict(Verdict::Correct) {
+        return Err(io::Error::other(reply.to_string()));
+    };
+    println!("{}", reply);
+
+    let path = aoc::root().join("answers.txt");
+    let mut answers = Answers::load(&path)?;
+    answers.set(day.year, day.day, "input.txt", part, &answer);
+    answers.save(&path)
+}
+
+// times the chosen days on their inputs, and keeps the results to compare the
+// next lot against. days without an input.txt are skipped, and said to be; an
+// input asked for by name has to be there
+fn run_bench(days: Vec<&Day>, input: Option<&str>, runs: usize, threshold: f64) -> io::Result<()> {
+    aoc::set_animate(false);
+    let path = aoc::root().join("bench-history.json");
+    let mut history = bench::load_history(&path)?;
+
+    let mut benches = Vec::new();
+    let mut failed = Vec::new();
+    let mut skipped = Vec::new();
+    for day in days {
+        let (named, input) = (input.is_some(), input.unwrap_or("input.txt"));
+        let contents = match fs::read_to_string(day.dir().join(input)) {
+            Ok(contents) => contents,
+            Err(e) if named || e.kind() != io::ErrorKind::NotFound => {
+                return Err(io::Error::new(e.kind(), format!("Couldn't read {}: {}", day.dir().join(input).display(), e)));
+            },
+            Err(_) => {
+                skipped.push(format!("{}/{}", day.year, day.day));
+                continue;
+            }
+        };
+        match bench::bench(day, input, &contents, runs) {
+            Ok(bench) => benches.push(bench),
+            Err(e) => failed.push(format!("{} day {}: {}", day.year, day.day, e))
+        };
+    };
+
+    let (table, regressions) = bench::table(&benches, &history, threshold);
+    print!("{}", table);
+    for failure in &failed {
+        eprintln!("{}", failure);
+    };
+    println!("{} days over {} runs, {} phases more than {}% slower than before", benches.len(), runs, regressions, threshold);
+    if !skipped.is_empty() {
+        println!("{} days skipped without an input.txt: {}", skipped.len(), skipped.join(", "));
+    };
+
+    // the run is kept either way, so the next one has something to compare to
+    history.push(Run::new(runs, benches));
+    bench::save_history(&path, &history)?;
+    if failed.is_empty() && regressions == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::other("Benchmark failed"))
+    }
+}
+
+fn find(year: &str, day: &str) -> io::Result<&'static Day> {
+    let (year, day) = match (year.parse(), day.parse()) {
+        (Ok(year), Ok(day)) => (year, day),
+        _ => usage()
+    };
+    aoc::find(year, day).ok_or_else(|| io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("No solution for {} day {}", year, day)
+    ))
+}
+
+// every day, a year's worth, or just the one. an input file has to be the
+// one day's
+fn select(args: &[&str], input: Option<&str>) -> io::Result<Vec<&'static Day>> {
+    match (args, input) {
+        ([], None) => Ok(aoc::DAYS.iter().collect()),
+        ([year], None) => Ok(aoc::year(year.parse().unwrap_or_else(|_| usage())).collect()),
+        ([year, day], _) => Ok(vec![find(year, day)?]),
+        _ => usage()
+    }
+}
+
+fn run(mut args: Vec<String>) -> io::Result<()> {
+    // everything after `--` belongs to the day
+    let options = match args.iter().position(|arg| arg == "--") {
+        Some(i) => args.split_off(i).into_iter().skip(1).collect(),
+        None => Vec::new()
+    };
+    let source = Source::take(&mut args).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        usage()
+    });
+    // `verify` and `bench` go by the name of a file in the day's directory
+    let input = || match &source {
+        Source::Default => None,
+        Source::File(path) => Some(path.to_string_lossy().into_owned()),
+        _ => usage()
+    };
+    let mut take_option = |flag| intcode::take_option(&mut args, flag).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        usage()
+    });
+    let runs = take_option("--runs");
+    let threshold = take_option("--threshold");
+    let format = take_option("--format");
+    if args.first().map(String::as_str) != Some("bench") && (runs.is_some() || threshold.is_some()) {
+        usage();
+    };
+    let format = match format.as_deref() {
+        None | Some("text") => Format::Text,
+        Some("json") if args.first().map(String::as_str) == Some("run") => Format::Json,
+        Some(_) => usage()
+    };
+    if args.first().map(String::as_str) != Some("run") && format != Format::Text {
+        usage();
+    };
+
+    match args.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+        ["run", rest @ .., "--all"] if rest.len() <= 1 => {
+            if source != Source::Default || !options.is_empty() {
+                usage();
+            };
+            run_all(select(rest, None)?, format)
+        },
+        ["run", year, day] => run_one(find(year, day)?, &source, options, format),
+        ["new", year, day] => {
+            let (year, day) = match (year.parse(), day.parse()) {
+                (Ok(year), Ok(day)) => (year, day),
+                _ => usage()
+            };
+            if source != Source::Default || !options.is_empty() {
+                usage();
+            };
+            for path in scaffold::scaffold(aoc::root(), year, day)? {
+                println!("Wrote {}", path.display());
+            };
+            Ok(())
+        },
+        ["fetch", year, rest @ ..] if source == Source::Default && options.is_empty() => {
+            let year = year.parse().unwrap_or_else(|_| usage());
+            let days = match rest {
+                [] => aoc::year(year).map(|day| day.day).collect(),
+                [day] => vec![day.parse().unwrap_or_else(|_| usage())],
+                _ => usage()
+            };
+            run_fetch(year, days)
+        },
+        ["submit", year, day, part] if source == Source::Default => {
+            let part = match part.parse() {
+                Ok(part @ 1..=2) => part,
+                _ => usage()
+            };
+            run_submit(find(year, day)?, part, options)
+        },
+        ["verify", rest @ ..] => {
+            let (update, rest) = match rest {
+                [rest @ .., "--update"] => (true, rest),
+                rest => (false, rest)
+            };
+            if !options.is_empty() {
+                usage();
+            };
+            let input = input();
+            run_verify(select(rest, input.as_deref())?, input.as_deref(), update)
+        },
+        ["bench", rest @ ..] => {
+            let runs = runs.map_or(Ok(10), |runs| runs.parse()).unwrap_or_else(|_| usage());
+            let threshold = threshold.map_or(Ok(20.0), |threshold| threshold.parse()).unwrap_or_else(|_| usage());
+            if runs == 0 || !options.is_empty() {
+                usage();
+            };
+            let input = input();
+            run_bench(select(rest, input.as_deref())?, input.as_deref(), runs, threshold)
+        },
+        _ => usage()
+    }
+}
+
+fn main() {
+    env_logger::init();
+
+    if let Err(e) = run(env::args().skip(1).collect()) {
+        eprintln!("{}", e);
+        process::exit(1);
+    };
+}
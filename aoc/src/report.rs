@@ -0,0 +1,86 @@
+use std::any::Any;
+
+use serde::{Serialize, Serializer};
+
+use crate::{Answer, Day, Solved};
+
+
+// numbers stay numbers, and an unsolved part has no answer at all
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Number(n) => serializer.serialize_i64(*n),
+            Answer::Text(text) => serializer.serialize_str(text),
+            Answer::Unsolved => serializer.serialize_none()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Ok,
+    Unsolved,
+    // the day couldn't be run, so neither part has an answer
+    Error
+}
+
+// how one part went, for `aoc run --format json`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    pub status: Status,
+    pub answer: Answer,
+    // part 1's includes parsing. there's none when the day couldn't be run
+    pub elapsed_ns: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    // anything the part had to say besides its answer, like a picture it
+    // couldn't read
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>
+}
+
+// a record for each part. details only get here if they were being collected
+pub fn records(day: &Day, solved: Result<Solved, String>) -> Vec<Record> {
+    let record = |part, status, answer, elapsed_ns: Option<u64>, error, details: String| Record {
+        year: day.year,
+        day: day.day,
+        part,
+        status,
+        answer,
+        elapsed_ns,
+        error,
+        details: Some(details).filter(|details| !details.is_empty())
+    };
+
+    match solved {
+        Ok(Solved { part1, part2, timings, details: [details1, details2] }) => {
+            let status = |answer: &Answer| if *answer == Answer::Unsolved { Status::Unsolved } else { Status::Ok };
+            vec![
+                record(1, status(&part1), part1, Some((timings.parse + timings.part1).as_nanos() as u64), None, details1),
+                record(2, status(&part2), part2, Some(timings.part2.as_nanos() as u64), None, details2)
+            ]
+        },
+        // whatever was said before it went wrong goes with part 1
+        Err(e) => vec![
+            record(1, Status::Error, Answer::Unsolved, None, Some(e.clone()), crate::take_details()),
+            record(2, Status::Error, Answer::Unsolved, None, Some(e), String::new())
+        ]
+    }
+}
+
+// what a day panicked with, as near as can be told
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .map_or_else(|| "panicked".to_string(), |message| format!("panicked: {}", message))
+}
+
+pub fn to_json(records: &[Record]) -> String {
+    serde_json::to_string_pretty(records).expect("Couldn't write records as JSON!")
+}
@@ -0,0 +1,121 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+
+// what `aoc new` starts a day off with
+const SOLUTION: &str = include_str!("../templates/solution.rs");
+const EXPECTED: &str = include_str!("../templates/expected.txt");
+
+const YEAR_HEADER: &str = "// each day's solution lives next to its input, up in the year's directory\n";
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+pub fn solution(day: u32) -> String {
+    SOLUTION.replace("DayN", &format!("Day{}", day))
+}
+
+// adds the day to a year's module, keeping the days in order
+pub fn register_module(module: &str, year: u32, day: u32) -> Result<String, io::Error> {
+    let entry = format!("#[path = \"../../{}/{}/solution.rs\"]\npub mod day{};\n", year, day, day);
+    let mut lines = module.lines().map(str::to_string).collect::<Vec<_>>();
+
+    let mut at = lines.len();
+    for (i, line) in lines.iter().enumerate() {
+        if let Some(other) = line.strip_prefix("pub mod day").and_then(|rest| rest.strip_suffix(';')) {
+            let other = other.parse::<u32>().map_err(|_| invalid(format!("Unexpected module {}", line)))?;
+            if other == day {
+                return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("{} day {} is already a module", year, day)));
+            } else if other > day && at == lines.len() {
+                // above its #[path]
+                at = i - 1;
+            };
+        };
+    };
+
+    lines.insert(at, entry.trim_end().to_string());
+    Ok(lines.join("\n") + "\n")
+}
+
+pub fn year_module(year: u32, day: u32) -> String {
+    register_module(&format!("{}\n", YEAR_HEADER), year, day).expect("Empty year already has the day!")
+}
+
+// adds a new year's module next to the others
+pub fn register_year(lib: &str, year: u32) -> Result<String, io::Error> {
+    let mut lines = lib.lines().map(str::to_string).collect::<Vec<_>>();
+    let last = lines
+        .iter()
+        .rposition(|line| line.starts_with("pub mod y") && line[9..].trim_end_matches(';').parse::<u32>().is_ok())
+        .ok_or_else(|| invalid("No year modules in lib.rs!".to_string()))?;
+    lines.insert(last + 1, format!("pub mod y{};", year));
+    Ok(lines.join("\n") + "\n")
+}
+
+// adds the day to the `days!` table, which is kept in order with a blank line
+// between the years
+pub fn register_day(lib: &str, year: u32, day: u32) -> Result<String, io::Error> {
+    let start = lib.find("days! {\n").ok_or_else(|| invalid("No days! table in lib.rs!".to_string()))? + "days! {\n".len();
+    let end = start + lib[start..].find("\n}\n").ok_or_else(|| invalid("days! table never ends!".to_string()))?;
+
+    let mut days = Vec::new();
+    for line in lib[start..end].lines().map(str::trim).filter(|line| !line.is_empty()) {
+        let mut fields = line.split_whitespace();
+        match (fields.next().map(str::parse::<u32>), fields.next().map(str::parse::<u32>)) {
+            (Some(Ok(y)), Some(Ok(d))) => days.push(((y, d), line.to_string())),
+            _ => return Err(invalid(format!("Unexpected line in days! table: {}", line)))
+        };
+    };
+    if days.iter().any(|(key, _)| *key == (year, day)) {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("{} day {} is already registered", year, day)));
+    };
+    days.push(((year, day), format!("{} {} => y{}::day{}::Day{},", year, day, year, day, day)));
+    days.sort();
+
+    let mut table = String::new();
+    let mut last_year = None;
+    for ((y, _), line) in days {
+        if last_year.is_some() && last_year != Some(y) {
+            table.push('\n');
+        };
+        table.push_str(&format!("    {}\n", line));
+        last_year = Some(y);
+    };
+    Ok(format!("{}{}{}", &lib[..start], table.trim_end_matches('\n'), &lib[end..]))
+}
+
+// writes out the day's solution and sample answers, and registers it with
+// the runner. gives back the files it touched
+pub fn scaffold(root: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>, io::Error> {
+    let dir = root.join(year.to_string()).join(day.to_string());
+    let solution_path = dir.join("solution.rs");
+    if solution_path.exists() {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("{} already exists", solution_path.display())));
+    };
+
+    // work everything out before writing anything, so a bad table doesn't
+    // leave a day half made
+    let src = root.join("aoc").join("src");
+    let lib_path = src.join("lib.rs");
+    let module_path = src.join(format!("y{}.rs", year));
+    let mut lib = register_day(&fs::read_to_string(&lib_path)?, year, day)?;
+    let module = if module_path.exists() {
+        register_module(&fs::read_to_string(&module_path)?, year, day)?
+    } else {
+        lib = register_year(&lib, year)?;
+        year_module(year, day)
+    };
+
+    fs::create_dir_all(&dir)?;
+    fs::write(&solution_path, solution(day))?;
+    let expected_path = dir.join("expected.txt");
+    if !expected_path.exists() {
+        fs::write(&expected_path, EXPECTED)?;
+    };
+    fs::write(&module_path, module)?;
+    fs::write(&lib_path, lib)?;
+
+    Ok(vec![solution_path, expected_path, module_path, lib_path])
+}
@@ -0,0 +1,125 @@
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+
+// what a part comes up with. most are numbers, some are words read off a
+// picture, and a few parts were never finished
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    Unsolved
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Unsolved => write!(f, "unsolved")
+        }
+    }
+}
+
+macro_rules! number {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Number(i64::try_from(n).expect("Answer doesn't fit in an i64!"))
+            }
+        })*
+    };
+}
+
+number!(i32, i64, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+
+    // takes the options given after `--` on the command line, for days that
+    // can be set up differently or do more than answer; most can't
+    fn configure(_input: &mut Self::Input, args: Vec<String>) -> Result<(), Box<dyn Error>> {
+        reject_options(&args)
+    }
+}
+
+// for whatever options are left once a day's taken the ones it knows
+pub fn reject_options(args: &[String]) -> Result<(), Box<dyn Error>> {
+    match args.first() {
+        Some(arg) => Err(format!("Unknown option {}", arg).into()),
+        None => Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration
+}
+
+pub struct Solved {
+    pub part1: Answer,
+    pub part2: Answer,
+    pub timings: Timings,
+    // what each part had to say, when it's being collected
+    pub details: [String; 2]
+}
+
+// configuring counts towards parsing, since some days read files then.
+// anything said while parsing goes with part 1
+pub fn solve<S: Solution>(input: &str, args: Vec<String>) -> Result<Solved, Box<dyn Error>> {
+    crate::take_details();
+    let start = Instant::now();
+    let mut input = S::parse(input)?;
+    S::configure(&mut input, args)?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let part1 = S::part1(&input);
+    let part1_time = start.elapsed();
+    let details1 = crate::take_details();
+
+    let start = Instant::now();
+    let part2 = S::part2(&input);
+    let part2_time = start.elapsed();
+    let details2 = crate::take_details();
+
+    Ok(Solved {
+        part1,
+        part2,
+        timings: Timings { parse, part1: part1_time, part2: part2_time },
+        details: [details1, details2]
+    })
+}
+
+// just the one part, for inputs that only make sense for it, like the
+// puzzle's examples
+pub fn solve_part<S: Solution>(input: &str, args: Vec<String>, part: u8) -> Result<Answer, Box<dyn Error>> {
+    let mut input = S::parse(input)?;
+    S::configure(&mut input, args)?;
+    match part {
+        1 => Ok(S::part1(&input)),
+        2 => Ok(S::part2(&input)),
+        _ => Err(format!("No part {}", part).into())
+    }
+}
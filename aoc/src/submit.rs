@@ -0,0 +1,251 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::fetch::{self, Config};
+
+
+// what the server made of an answer it took
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    // wrong, without a hint which way
+    Wrong
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong"
+        })
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too-high" => Ok(Verdict::TooHigh),
+            "too-low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            _ => Err(format!("Unknown verdict {}", s))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reply {
+    Verdict(Verdict),
+    // answered too recently, so this one wasn't looked at
+    Wait(Duration),
+    // the part's already done, or the one before it isn't
+    WrongLevel
+}
+
+impl fmt::Display for Reply {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reply::Verdict(Verdict::Correct) => write!(f, "That's the right answer"),
+            Reply::Verdict(Verdict::TooHigh) => write!(f, "Too high"),
+            Reply::Verdict(Verdict::TooLow) => write!(f, "Too low"),
+            Reply::Verdict(Verdict::Wrong) => write!(f, "Wrong"),
+            Reply::Wait(wait) => write!(f, "Answered too recently, {}s left to wait", wait.as_secs()),
+            Reply::WrongLevel => write!(f, "Not the part being solved; is it already done?")
+        }
+    }
+}
+
+// "You have 1m 5s left to wait" and the like
+fn wait(page: &str) -> Option<Duration> {
+    let start = page.find("You have ")? + "You have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+
+    let mut secs = 0;
+    for amount in page[start..end].split_whitespace() {
+        let (n, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+        let n: u64 = n.parse().ok()?;
+        secs += match unit {
+            "h" => n * 3600,
+            "m" => n * 60,
+            "s" => n,
+            _ => return None
+        };
+    };
+    Some(Duration::from_secs(secs))
+}
+
+// reads the page the server sends back after an answer
+pub fn parse_reply(page: &str) -> Result<Reply, String> {
+    if page.contains("That's the right answer") {
+        Ok(Reply::Verdict(Verdict::Correct))
+    } else if page.contains("That's not the right answer") {
+        Ok(Reply::Verdict(if page.contains("too high") {
+            Verdict::TooHigh
+        } else if page.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }))
+    } else if page.contains("You gave an answer too recently") {
+        wait(page).map(Reply::Wait).ok_or_else(|| "Told to wait, but not for how long".to_string())
+    } else if page.contains("You don't seem to be solving the right level") {
+        Ok(Reply::WrongLevel)
+    } else {
+        Err("Couldn't make out the reply".to_string())
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    pub verdict: Verdict,
+    pub answer: String
+}
+
+// every answer that's been given a verdict, one per line:
+//
+//     <year> <day> <part> <verdict> <answer>
+//
+// so a wrong one never gets sent twice, and nothing past a known bound does
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Log {
+    pub submissions: Vec<Submission>
+}
+
+fn invalid(line: usize, message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("line {}: {}", line, message))
+}
+
+impl Log {
+    pub fn parse(text: &str) -> Result<Self, io::Error> {
+        let mut submissions = Vec::new();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            };
+
+            let fields = line.splitn(5, ' ').collect::<Vec<_>>();
+            if fields.len() < 5 {
+                return Err(invalid(i + 1, "expected <year> <day> <part> <verdict> <answer>"));
+            };
+            let number = |field: &str| field.parse().map_err(|_| invalid(i + 1, &format!("bad number {:?}", field)));
+            let part = match fields[2].parse() {
+                Ok(part @ 1..=2) => part,
+                _ => return Err(invalid(i + 1, &format!("bad part {:?}", fields[2])))
+            };
+            submissions.push(Submission {
+                year: number(fields[0])?,
+                day: number(fields[1])?,
+                part,
+                verdict: fields[3].parse().map_err(|e: String| invalid(i + 1, &e))?,
+                answer: fields[4].to_string()
+            });
+        };
+        Ok(Log { submissions })
+    }
+
+    // a missing file is just an empty log
+    pub fn load(path: &Path) -> Result<Self, io::Error> {
+        match fs::read_to_string(path) {
+            Ok(text) => Log::parse(&text),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Log::default()),
+            Err(e) => Err(e)
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), io::Error> {
+        fs::write(path, self.to_string())
+    }
+
+    // why the answer shouldn't be sent, if there's a reason
+    pub fn check(&self, year: u32, day: u32, part: u8, answer: &str) -> Result<(), String> {
+        let number = answer.parse::<i64>().ok();
+        for submission in self.submissions.iter().filter(|s| s.year == year && s.day == day && s.part == part) {
+            let bound = submission.answer.parse::<i64>().ok();
+            match (submission.verdict, number, bound) {
+                (Verdict::Correct, _, _) => {
+                    return Err(format!("Part {} was already solved with {}", part, submission.answer));
+                },
+                _ if submission.answer == answer => {
+                    return Err(format!("{} was already {}", answer, submission.verdict));
+                },
+                (Verdict::TooHigh, Some(n), Some(high)) if n >= high => {
+                    return Err(format!("{} isn't below {}, which was too high", n, high));
+                },
+                (Verdict::TooLow, Some(n), Some(low)) if n <= low => {
+                    return Err(format!("{} isn't above {}, which was too low", n, low));
+                },
+                _ => ()
+            };
+        };
+        Ok(())
+    }
+}
+
+impl fmt::Display for Log {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# year day part verdict answer")?;
+        for s in &self.submissions {
+            writeln!(f, "{} {} {} {} {}", s.year, s.day, s.part, s.verdict, s.answer)?;
+        };
+        Ok(())
+    }
+}
+
+// submissions.txt at the top of the repo, ignored by git like the config
+pub fn log_path() -> PathBuf {
+    crate::root().join("submissions.txt")
+}
+
+
+pub struct Submitter {
+    config: Config,
+    // where the time of the last request is kept, as for fetching
+    cache: PathBuf,
+    agent: ureq::Agent
+}
+
+impl Submitter {
+    pub fn new(config: Config, cache: &Path) -> Self {
+        Submitter { agent: fetch::agent(&config), config, cache: cache.to_path_buf() }
+    }
+
+    // sends the answer unless the log already rules it out, and logs the
+    // verdict. being told to wait isn't a verdict, so it's not logged
+    pub fn submit(&self, log: &mut Log, year: u32, day: u32, part: u8, answer: &str) -> Result<Reply, io::Error> {
+        log.check(year, day, part, answer).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        let url = format!("{}/{}/day/{}/answer", self.config.base_url.trim_end_matches('/'), year, day);
+        let session = self.config.session.as_ref().ok_or_else(|| io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("No session in {} to submit to {} with", fetch::config_path().display(), url)
+        ))?;
+
+        fetch::throttle(&self.cache, self.config.interval)?;
+        let response = self.agent
+            .post(&url)
+            .header("Cookie", format!("session={}", session))
+            .send_form([("level", part.to_string()), ("answer", answer.to_string())]);
+        let page = fetch::body(&url, response)?;
+        let reply = parse_reply(&page).map_err(io::Error::other)?;
+
+        if let Reply::Verdict(verdict) = reply {
+            log.submissions.push(Submission { year, day, part, verdict, answer: answer.to_string() });
+        };
+        Ok(reply)
+    }
+}
@@ -0,0 +1,172 @@
+use std::fmt;
+use std::fs;
+use std::panic;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::report;
+use crate::{Answer, Answers, Day};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail,
+    // nothing recorded to check against yet
+    Missing,
+    // the part was never finished, so there's nothing to check
+    Unsolved,
+    // the day couldn't read or parse its input, or panicked
+    Error
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let word = match self {
+            Outcome::Pass => "pass",
+            Outcome::Fail => "FAIL",
+            Outcome::Missing => "missing",
+            Outcome::Unsolved => "unsolved",
+            Outcome::Error => "ERROR"
+        };
+        f.pad(word)
+    }
+}
+
+pub struct Check {
+    pub year: u32,
+    pub day: u32,
+    pub input: String,
+    pub part: u8,
+    pub expected: Option<String>,
+    // or why there isn't one
+    pub answer: Result<Answer, String>,
+    pub time: Duration,
+    pub outcome: Outcome
+}
+
+fn solve(day: &Day, path: &Path) -> Result<crate::Solved, String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|e| format!("Couldn't read {}: {}", path.display(), e))?;
+    match panic::catch_unwind(|| (day.solve)(&contents, Vec::new())) {
+        Ok(solved) => solved.map_err(|e| e.to_string()),
+        Err(payload) => Err(report::panic_message(payload.as_ref()))
+    }
+}
+
+// runs a day on one of its inputs, and checks both parts against the store
+pub fn check(day: &Day, input: &str, answers: &Answers) -> Vec<Check> {
+    let solved = solve(day, &day.dir().join(input));
+
+    (1..=2).map(|part| {
+        let expected = answers.get(day.year, day.day, input, part).map(str::to_string);
+        let (answer, time) = match &solved {
+            Ok(solved) if part == 1 => (Ok(solved.part1.clone()), solved.timings.parse + solved.timings.part1),
+            Ok(solved) => (Ok(solved.part2.clone()), solved.timings.part2),
+            Err(e) => (Err(e.clone()), Duration::default())
+        };
+        let outcome = match (&answer, &expected) {
+            (Err(_), _) => Outcome::Error,
+            (Ok(Answer::Unsolved), _) => Outcome::Unsolved,
+            (Ok(_), None) => Outcome::Missing,
+            (Ok(answer), Some(expected)) if answer.to_string() == *expected => Outcome::Pass,
+            (Ok(_), Some(_)) => Outcome::Fail
+        };
+        Check { year: day.year, day: day.day, input: input.to_string(), part, expected, answer, time, outcome }
+    }).collect()
+}
+
+// the inputs with answers recorded, plus the day's own input if it's there
+pub fn inputs(day: &Day, answers: &Answers) -> Vec<String> {
+    let mut inputs = answers.inputs(day.year, day.day)
+        .into_iter()
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    if day.input_path().exists() && !inputs.iter().any(|input| input == "input.txt") {
+        inputs.push("input.txt".to_string());
+    };
+    inputs.sort();
+    inputs
+}
+
+// stands in for a day with nothing to run it on, so it still shows up in the
+// report rather than going unmentioned
+pub fn no_inputs(day: &Day) -> Vec<Check> {
+    (1..=2).map(|part| Check {
+        year: day.year,
+        day: day.day,
+        input: "-".to_string(),
+        part,
+        expected: None,
+        answer: Err(format!("no answers recorded and no {}", day.input_path().display())),
+        time: Duration::default(),
+        outcome: Outcome::Missing
+    }).collect()
+}
+
+// records what was found for parts with nothing recorded or a different
+// answer, for after a deliberate change. errors and unsolved parts are left be
+pub fn update(answers: &mut Answers, checks: &[Check]) -> usize {
+    let mut updated = 0;
+    for check in checks {
+        if let (Outcome::Missing, Ok(answer)) | (Outcome::Fail, Ok(answer)) = (check.outcome, &check.answer) {
+            answers.set(check.year, check.day, &check.input, check.part, &answer.to_string());
+            updated += 1;
+        };
+    };
+    updated
+}
+
+pub(crate) fn format_time(time: Duration) -> String {
+    if time.as_secs() > 0 {
+        format!("{:.2}s", time.as_secs_f64())
+    } else if time.as_millis() > 0 {
+        format!("{}ms", time.as_millis())
+    } else {
+        format!("{}µs", time.as_micros())
+    }
+}
+
+pub fn table(checks: &[Check]) -> String {
+    let mut rows = vec![
+        ["year", "day", "input", "part", "expected", "answer", "time", "result"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>()
+    ];
+    for check in checks {
+        rows.push(vec![
+            check.year.to_string(),
+            check.day.to_string(),
+            check.input.clone(),
+            check.part.to_string(),
+            check.expected.clone().unwrap_or_else(|| "-".to_string()),
+            match &check.answer {
+                Ok(answer) => answer.to_string(),
+                Err(e) => e.clone()
+            },
+            format_time(check.time),
+            check.outcome.to_string()
+        ]);
+    };
+    columns(&rows)
+}
+
+// lines the rows up under the first one
+pub(crate) fn columns(rows: &[Vec<String>]) -> String {
+    let widths = (0..rows[0].len())
+        .map(|i| rows.iter().map(|row| row[i].chars().count()).max().unwrap_or(0))
+        .collect::<Vec<_>>();
+
+    let mut table = String::new();
+    for row in rows {
+        let line = row.iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ");
+        table.push_str(line.trim_end());
+        table.push('\n');
+    };
+    table
+}
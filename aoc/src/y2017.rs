@@ -0,0 +1,6 @@
+// each day's solution lives next to its input, up in the year's directory
+
+#[path = "../../2017/1/solution.rs"]
+pub mod day1;
+#[path = "../../2017/2/solution.rs"]
+pub mod day2;
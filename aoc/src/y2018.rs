@@ -0,0 +1,28 @@
+// each day's solution lives next to its input, up in the year's directory
+
+#[path = "../../2018/1/solution.rs"]
+pub mod day1;
+#[path = "../../2018/2/solution.rs"]
+pub mod day2;
+#[path = "../../2018/3/solution.rs"]
+pub mod day3;
+#[path = "../../2018/4/solution.rs"]
+pub mod day4;
+#[path = "../../2018/5/solution.rs"]
+pub mod day5;
+#[path = "../../2018/6/solution.rs"]
+pub mod day6;
+#[path = "../../2018/7/solution.rs"]
+pub mod day7;
+#[path = "../../2018/8/solution.rs"]
+pub mod day8;
+#[path = "../../2018/9/solution.rs"]
+pub mod day9;
+#[path = "../../2018/10/solution.rs"]
+pub mod day10;
+#[path = "../../2018/11/solution.rs"]
+pub mod day11;
+#[path = "../../2018/12/solution.rs"]
+pub mod day12;
+#[path = "../../2018/13/solution.rs"]
+pub mod day13;
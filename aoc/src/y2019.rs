@@ -0,0 +1,30 @@
+// each day's solution lives next to its input, up in the year's directory
+
+#[path = "../../2019/2/solution.rs"]
+pub mod day2;
+#[path = "../../2019/3/solution.rs"]
+pub mod day3;
+#[path = "../../2019/4/solution.rs"]
+pub mod day4;
+#[path = "../../2019/5/solution.rs"]
+pub mod day5;
+#[path = "../../2019/6/solution.rs"]
+pub mod day6;
+#[path = "../../2019/7/solution.rs"]
+pub mod day7;
+#[path = "../../2019/8/solution.rs"]
+pub mod day8;
+#[path = "../../2019/9/solution.rs"]
+pub mod day9;
+#[path = "../../2019/10/solution.rs"]
+pub mod day10;
+#[path = "../../2019/11/solution.rs"]
+pub mod day11;
+#[path = "../../2019/12/solution.rs"]
+pub mod day12;
+#[path = "../../2019/13/solution.rs"]
+pub mod day13;
+#[path = "../../2019/14/solution.rs"]
+pub mod day14;
+#[path = "../../2019/15/solution.rs"]
+pub mod day15;
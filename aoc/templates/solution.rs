@@ -0,0 +1,21 @@
+use std::error::Error;
+
+use crate::{Answer, Solution};
+
+pub struct DayN;
+
+impl Solution for DayN {
+    type Input = Vec<String>;
+
+    fn parse(contents: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(contents.lines().filter(|line| !line.is_empty()).map(str::to_string).collect())
+    }
+
+    fn part1(_lines: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part2(_lines: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
@@ -0,0 +1,35 @@
+use aoc::Answers;
+
+
+#[test]
+fn answers_round_trip() {
+    let text = "\
+# year day input part answer
+2018 13 mark-input.txt 2 84,90
+2019 8 input.txt 2 CEKUA
+2018 13 mark-input.txt 1 41,22
+";
+    let answers = Answers::parse(text).unwrap();
+    assert_eq!(answers.get(2018, 13, "mark-input.txt", 1), Some("41,22"));
+    assert_eq!(answers.get(2018, 13, "input.txt", 1), None);
+    assert_eq!(answers.inputs(2018, 13), vec!["mark-input.txt"]);
+
+    let saved = answers.to_string();
+    assert!(saved.find("2018 13 mark-input.txt 1").unwrap() < saved.find("2018 13 mark-input.txt 2").unwrap());
+    assert_eq!(Answers::parse(&saved).unwrap(), answers);
+}
+
+#[test]
+fn answers_keep_spaces() {
+    let mut answers = Answers::default();
+    answers.set(2019, 9, "input.txt", 1, "203 0");
+    let answers = Answers::parse(&answers.to_string()).unwrap();
+    assert_eq!(answers.get(2019, 9, "input.txt", 1), Some("203 0"));
+}
+
+#[test]
+fn bad_answers() {
+    assert!(Answers::parse("2019 2 input.txt 1").is_err());
+    assert!(Answers::parse("2019 2 input.txt 3 10").is_err());
+    assert!(Answers::parse("twenty 2 input.txt 1 10").is_err());
+}
@@ -0,0 +1,52 @@
+use std::time::Duration;
+
+use aoc::bench::{self, previous, Bench, Run, Stats};
+
+
+fn stats(median_ns: u64, spread_ns: u64) -> Stats {
+    Stats { median_ns, spread_ns }
+}
+
+fn bench(day: u32, part2: Stats) -> Bench {
+    Bench { year: 2019, day, input: "input.txt".to_string(), parse: stats(0, 0), part1: stats(0, 0), part2 }
+}
+
+#[test]
+fn median_and_spread() {
+    let micros = |times: &[u64]| times.iter().map(|&t| Duration::from_micros(t)).collect::<Vec<_>>();
+    assert_eq!(Stats::of(&micros(&[30, 10, 20])), stats(20_000, 20_000));
+    assert_eq!(Stats::of(&micros(&[40, 10, 20, 30])), stats(25_000, 30_000));
+    assert_eq!(Stats::of(&micros(&[7])), stats(7_000, 0));
+}
+
+#[test]
+fn regressions_beat_the_noise() {
+    let before = stats(1000, 100);
+    assert!(stats(1300, 50).regressed(&before, 20.0));
+    assert!(!stats(1100, 50).regressed(&before, 20.0));
+    assert!(!stats(700, 50).regressed(&before, 20.0));
+    // slower by more than the threshold, but not by more than it varied
+    assert!(!stats(1300, 50).regressed(&stats(1000, 400), 20.0));
+}
+
+#[test]
+fn compared_with_the_latest_run() {
+    let history = vec![
+        Run { timestamp: 1, runs: 5, benches: vec![bench(12, stats(10, 0)), bench(13, stats(20, 0))] },
+        Run { timestamp: 2, runs: 5, benches: vec![bench(12, stats(30, 0))] }
+    ];
+    assert_eq!(previous(&history, &bench(12, stats(0, 0))).unwrap().part2, stats(30, 0));
+    assert_eq!(previous(&history, &bench(13, stats(0, 0))).unwrap().part2, stats(20, 0));
+    assert!(previous(&history, &bench(14, stats(0, 0))).is_none());
+
+    let mut other = bench(12, stats(0, 0));
+    other.input = "sample1.txt".to_string();
+    assert!(previous(&history, &other).is_none());
+}
+
+#[test]
+fn panics_say_why() {
+    // there's no opcode 42, which day 9 won't stand for
+    let day = aoc::find(2019, 9).unwrap();
+    assert_eq!(bench::bench(day, "input.txt", "42", 1).unwrap_err(), "panicked: Node A: unknown opcode 42 at ip 0");
+}
@@ -0,0 +1,62 @@
+// shared by the tests that need a server, which don't all look at everything
+#![allow(dead_code)]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+
+// what a stub server was sent
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub cookie: Option<String>,
+    pub body: String
+}
+
+// a puzzle server on some free port, answering each request with the status
+// and page `respond` comes up with. gives back its url and what it was sent
+pub fn serve<F>(respond: F) -> (String, Arc<Mutex<Vec<Request>>>)
+where F: Fn(&Request) -> (&'static str, String) + Send + 'static {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+
+    let seen = Arc::clone(&requests);
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut head = Vec::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim_end().is_empty() {
+                    break;
+                };
+                head.push(line.trim_end().to_string());
+            };
+
+            let header = |name: &str| head.iter().find_map(|line| {
+                let (key, value) = line.split_once(": ")?;
+                if key.eq_ignore_ascii_case(name) { Some(value.to_string()) } else { None }
+            });
+            let mut body = vec![0; header("content-length").map_or(0, |length| length.parse().unwrap())];
+            reader.read_exact(&mut body).unwrap();
+
+            let mut request_line = head[0].split(' ');
+            let request = Request {
+                method: request_line.next().unwrap().to_string(),
+                path: request_line.next().unwrap().to_string(),
+                cookie: header("cookie"),
+                body: String::from_utf8(body).unwrap()
+            };
+            let (status, page) = respond(&request);
+            seen.lock().unwrap().push(request);
+            write!(stream, "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, page.len(), page).unwrap();
+        };
+    });
+
+    (url, requests)
+}
@@ -0,0 +1,109 @@
+use std::env;
+use std::fs;
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use aoc::fetch::{Config, Fetcher};
+
+mod common;
+use common::Request;
+
+
+// answers `/<year>/day/<day>/input` with "input for <year> <day>", or a 404
+// for day 25
+fn serve() -> (String, Arc<Mutex<Vec<Request>>>) {
+    common::serve(|request| {
+        let parts = request.path.split('/').collect::<Vec<_>>();
+        match parts.as_slice() {
+            ["", _, "day", "25", "input"] => ("404 Not Found", "no".to_string()),
+            ["", year, "day", day, "input"] => ("200 OK", format!("input for {} {}\n", year, day)),
+            _ => ("400 Bad Request", "what".to_string())
+        }
+    })
+}
+
+fn cache(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-fetch-test-{}", name));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+fn config(url: &str) -> Config {
+    Config { session: Some("cookie".to_string()), base_url: url.to_string(), interval: Duration::from_millis(0), ..Config::default() }
+}
+
+#[test]
+fn fetched_once_then_cached() {
+    let (url, requests) = serve();
+    let cache = cache("once");
+    let fetcher = Fetcher::new(config(&url), &cache);
+
+    assert!(!fetcher.is_cached(2019, 4));
+    assert_eq!(fetcher.input(2019, 4).unwrap(), "input for 2019 4\n");
+    assert_eq!(fetcher.input(2019, 4).unwrap(), "input for 2019 4\n");
+    assert_eq!(fs::read_to_string(cache.join("2019/4/input.txt")).unwrap(), "input for 2019 4\n");
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!((requests[0].method.as_str(), requests[0].path.as_str()), ("GET", "/2019/day/4/input"));
+    assert_eq!(requests[0].cookie.as_deref(), Some("session=cookie"));
+}
+
+#[test]
+fn warm_cache_needs_no_server() {
+    // nothing's listening here any more, and there's no session either
+    let url = format!("http://{}", TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap());
+    let cache = cache("warm");
+    fs::create_dir_all(cache.join("2018/11")).unwrap();
+    fs::write(cache.join("2018/11/input.txt"), "18\n").unwrap();
+
+    let fetcher = Fetcher::new(Config { session: None, ..config(&url) }, &cache);
+    assert_eq!(fetcher.input(2018, 11).unwrap(), "18\n");
+    assert!(fetcher.input(2018, 12).is_err());
+}
+
+#[test]
+fn failures_are_not_cached() {
+    let (url, requests) = serve();
+    let cache = cache("failures");
+    let fetcher = Fetcher::new(config(&url), &cache);
+
+    let error = fetcher.input(2019, 25).unwrap_err().to_string();
+    assert!(error.contains("404"), "{}", error);
+    assert!(!fetcher.is_cached(2019, 25));
+
+    let fetcher = Fetcher::new(Config { session: None, ..config(&url) }, &cache);
+    assert!(fetcher.input(2019, 3).is_err());
+    assert_eq!(requests.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn requests_are_spaced_out() {
+    let (url, requests) = serve();
+    let cache = cache("spaced");
+    let fetcher = Fetcher::new(Config { interval: Duration::from_millis(300), ..config(&url) }, &cache);
+
+    let start = Instant::now();
+    fetcher.input(2019, 1).unwrap();
+    fetcher.input(2019, 2).unwrap();
+    fetcher.input(2019, 2).unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(300));
+    assert!(start.elapsed() < Duration::from_millis(600));
+    assert_eq!(requests.lock().unwrap().len(), 2);
+}
+
+#[test]
+fn config_file() {
+    let config = Config::parse("# mine\nsession = abc123\n\ninterval = 0.5\n").unwrap();
+    assert_eq!(config.session.as_deref(), Some("abc123"));
+    assert_eq!(config.interval, Duration::from_millis(500));
+    assert_eq!(config.base_url, "https://adventofcode.com");
+
+    assert!(Config::parse("session abc").is_err());
+    assert!(Config::parse("colour = blue").is_err());
+    assert!(Config::parse("interval = soon").is_err());
+    for interval in ["-1", "inf", "NaN", "1e300"] {
+        assert!(Config::parse(&format!("interval = {}", interval)).is_err(), "{}", interval);
+    };
+}
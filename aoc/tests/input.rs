@@ -0,0 +1,49 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use aoc::input::Source;
+
+
+fn take(args: &[&str]) -> (Result<Source, String>, Vec<String>) {
+    let mut args = args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+    (Source::take(&mut args), args)
+}
+
+#[test]
+fn sources_from_arguments() {
+    assert_eq!(take(&["run", "2019", "4"]), (Ok(Source::Default), vec!["run".into(), "2019".into(), "4".into()]));
+    assert_eq!(take(&["--input", "-", "run"]), (Ok(Source::Stdin), vec!["run".into()]));
+    assert_eq!(take(&["run", "--input", "a.txt"]), (Ok(Source::File("a.txt".into())), vec!["run".into()]));
+    assert_eq!(take(&["--inline", "18", "run"]), (Ok(Source::Inline("18".into())), vec!["run".into()]));
+    assert!(take(&["run", "--input"]).0.is_err());
+    assert!(take(&["--input", "a.txt", "--inline", "18"]).0.is_err());
+}
+
+#[test]
+fn every_source_reads_the_same() {
+    let day = aoc::find(2019, 2).unwrap();
+    let file = Source::File(day.input_path()).read(day).unwrap();
+    assert_eq!(Source::Default.read(day).unwrap(), file);
+    assert_eq!(Source::Inline(file.clone()).read(day).unwrap(), file);
+    assert!(Source::File(day.dir().join("nope.txt")).read(day).is_err());
+}
+
+fn aoc(args: &[&str], stdin: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin.as_bytes()).unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success(), "aoc {:?} failed", args);
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn runs_from_a_pipe() {
+    assert_eq!(aoc(&["run", "2019", "4", "--input", "-"], "112233-112240\n"), "Part 1: 7\nPart 2: 7\n");
+    assert_eq!(aoc(&["run", "2019", "4", "--inline", "112233-112240"], ""), "Part 1: 7\nPart 2: 7\n");
+    assert_eq!(aoc(&["run", "2019", "14", "--input", "-"], "10 ORE => 10 A\n1 ORE => 1 B\n7 A, 1 B => 1 C\n7 A, 1 C => 1 D\n7 A, 1 D => 1 E\n7 A, 1 E => 1 FUEL\n"), "Part 1: 31\nPart 2: unsolved\n");
+}
@@ -0,0 +1,86 @@
+use std::process::Command;
+use std::time::Duration;
+
+use serde_json::{json, Value};
+
+use aoc::report::{self, Status};
+use aoc::{Answer, Solved, Timings};
+
+
+fn solved(part1: Answer, part2: Answer, details: [&str; 2]) -> Solved {
+    Solved {
+        part1,
+        part2,
+        timings: Timings { parse: Duration::from_nanos(5), part1: Duration::from_nanos(10), part2: Duration::from_nanos(20) },
+        details: [details[0].to_string(), details[1].to_string()]
+    }
+}
+
+#[test]
+fn a_record_for_each_part() {
+    let day = aoc::find(2019, 8).unwrap();
+    let records = report::records(day, Ok(solved(1548.into(), Answer::Unsolved, ["", "⬜⬛\n"])));
+
+    assert_eq!(records.len(), 2);
+    assert_eq!((records[0].part, records[0].status, records[0].elapsed_ns), (1, Status::Ok, Some(15)));
+    assert_eq!((records[1].part, records[1].status, records[1].elapsed_ns), (2, Status::Unsolved, Some(20)));
+    assert_eq!(records[0].details, None);
+    assert_eq!(records[1].details.as_deref(), Some("⬜⬛\n"));
+
+    let records = report::records(day, Err("no input".to_string()));
+    assert!(records.iter().all(|record| record.status == Status::Error && record.elapsed_ns.is_none()));
+    assert!(records.iter().all(|record| record.error.as_deref() == Some("no input")));
+}
+
+#[test]
+fn answers_keep_their_type() {
+    let day = aoc::find(2019, 11).unwrap();
+    let records = report::records(day, Ok(solved(1907.into(), "ABEKZGFG".into(), ["", ""])));
+    let json: Value = serde_json::from_str(&report::to_json(&records)).unwrap();
+    assert_eq!(json, json!([
+        { "year": 2019, "day": 11, "part": 1, "status": "ok", "answer": 1907, "elapsed_ns": 15 },
+        { "year": 2019, "day": 11, "part": 2, "status": "ok", "answer": "ABEKZGFG", "elapsed_ns": 20 }
+    ]));
+
+    let records = report::records(day, Err("no input".to_string()));
+    let json: Value = serde_json::from_str(&report::to_json(&records)).unwrap();
+    assert_eq!(json[1], json!({
+        "year": 2019, "day": 11, "part": 2, "status": "error", "answer": null, "elapsed_ns": null, "error": "no input"
+    }));
+}
+
+fn aoc(args: &[&str]) -> (bool, Value) {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc")).args(args).output().unwrap();
+    (output.status.success(), serde_json::from_slice(&output.stdout).expect("Not JSON!"))
+}
+
+#[test]
+fn runs_as_json() {
+    let (ok, json) = aoc(&["run", "2019", "4", "--inline", "112233-112240", "--format", "json"]);
+    assert!(ok);
+    assert_eq!(json.as_array().unwrap().len(), 2);
+    assert_eq!((&json[0]["answer"], &json[1]["answer"]), (&json!(7), &json!(7)));
+    assert!(json[1]["elapsed_ns"].is_u64());
+
+    // still a failure, but one that says so in JSON too
+    let (ok, json) = aoc(&["run", "2019", "4", "--inline", "soon", "--format", "json"]);
+    assert!(!ok);
+    assert_eq!(json[0]["status"], "error");
+}
+
+#[test]
+fn panics_are_errors_too() {
+    // there's no opcode 42, which day 9 won't stand for
+    let (ok, json) = aoc(&["run", "2019", "9", "--inline", "42", "--format", "json"]);
+    assert!(!ok);
+    assert_eq!(json[0]["status"], "error");
+    assert_eq!(json[0]["error"], "panicked: Node A: unknown opcode 42 at ip 0");
+}
+
+#[test]
+fn animations_turn_into_frames() {
+    // nothing gets drawn for JSON, so the replay comes out in the details
+    let (ok, json) = aoc(&["run", "2019", "11", "--format", "json", "--", "--replay"]);
+    assert!(ok);
+    assert!(json[1]["details"].as_str().unwrap().starts_with("Step 0: painted"));
+}
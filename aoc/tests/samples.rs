@@ -0,0 +1,19 @@
+use aoc::input::Source;
+
+
+// one test per part of every sample, written out by build.rs
+include!(concat!(env!("OUT_DIR"), "/samples.rs"));
+
+fn check(year: u32, day: u32, sample: &str, part: u8, expected: &str) {
+    let day = aoc::find(year, day).expect("No solution for the sample!");
+    let path = day.dir().join(sample);
+    let contents = Source::File(path.clone()).read(day).expect("Couldn't read sample!");
+    let answer = (day.solve_part)(&contents, Vec::new(), part).expect("Couldn't solve sample!");
+    assert_eq!(answer.to_string(), expected, "{} part {}", path.display(), part);
+}
+
+// unused once every sample has its answers
+#[allow(dead_code)]
+fn unchecked(year: u32, day: u32, sample: &str, part: u8) {
+    panic!("Nothing to check {}/{}/{} part {} against", year, day, sample, part);
+}
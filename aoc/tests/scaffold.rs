@@ -0,0 +1,61 @@
+use aoc::scaffold::{register_day, register_module, register_year, solution, year_module};
+pub use aoc::{Answer, Solution};
+
+// so the template has to keep compiling against the trait
+#[path = "../templates/solution.rs"]
+mod template;
+
+
+const LIB: &str = "\
+pub mod y2018;
+pub mod y2019;
+mod solution;
+
+days! {
+    2018 1 => y2018::day1::Day1,
+
+    2019 2 => y2019::day2::Day2,
+    2019 5 => y2019::day5::Day5,
+}
+
+pub fn find() {}
+";
+
+#[test]
+fn template_solves_nothing() {
+    assert_eq!(template::DayN::part1(&template::DayN::parse("a\n\nb\n").unwrap()), Answer::Unsolved);
+    assert!(solution(16).contains("pub struct Day16;"));
+    assert!(!solution(16).contains("DayN"));
+}
+
+#[test]
+fn days_go_in_order() {
+    let lib = register_day(LIB, 2019, 3).unwrap();
+    assert!(lib.contains("    2019 2 => y2019::day2::Day2,\n    2019 3 => y2019::day3::Day3,\n    2019 5 => y2019::day5::Day5,\n}"));
+
+    let lib = register_day(LIB, 2020, 1).unwrap();
+    assert!(lib.contains("    2019 5 => y2019::day5::Day5,\n\n    2020 1 => y2020::day1::Day1,\n}\n\npub fn find"));
+
+    assert!(register_day(LIB, 2019, 5).is_err());
+}
+
+#[test]
+fn new_years_get_a_module() {
+    let lib = register_year(LIB, 2020).unwrap();
+    assert!(lib.starts_with("pub mod y2018;\npub mod y2019;\npub mod y2020;\nmod solution;\n"));
+
+    let module = year_module(2020, 1);
+    assert!(module.ends_with("\n\n#[path = \"../../2020/1/solution.rs\"]\npub mod day1;\n"));
+}
+
+#[test]
+fn modules_go_in_order() {
+    let module = year_module(2019, 2);
+    let module = register_module(&module, 2019, 10).unwrap();
+    let module = register_module(&module, 2019, 1).unwrap();
+    let days = module.lines().filter(|line| line.starts_with("pub mod")).collect::<Vec<_>>();
+    assert_eq!(days, vec!["pub mod day1;", "pub mod day2;", "pub mod day10;"]);
+    assert!(module.contains("#[path = \"../../2019/1/solution.rs\"]\npub mod day1;\n#[path = \"../../2019/2/solution.rs\"]"));
+
+    assert!(register_module(&module, 2019, 10).is_err());
+}
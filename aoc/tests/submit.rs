@@ -0,0 +1,122 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use aoc::fetch::Config;
+use aoc::submit::{parse_reply, Log, Reply, Submission, Submitter, Verdict};
+
+mod common;
+use common::Request;
+
+
+const RIGHT: &str = "<article><p>That's the right answer!  You are <em>one gold star</em> closer.</p></article>";
+const HIGH: &str = "<article><p>That's not the right answer; your answer is too high.  If you're stuck, \
+    make sure you're using the full input data. Please wait one minute before trying again.</p></article>";
+const LOW: &str = "<article><p>That's not the right answer; your answer is too low.</p></article>";
+const WRONG: &str = "<article><p>That's not the right answer.  If you're stuck, make sure you're using \
+    the full input data.</p></article>";
+const WAIT: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer \
+    before trying again.  You have 1m 5s left to wait.</p></article>";
+const LEVEL: &str = "<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>";
+
+// a puzzle whose answer is 42, and that's sulking about answers of "soon"
+fn serve() -> (String, Arc<Mutex<Vec<Request>>>) {
+    common::serve(|request| {
+        let answer = request.body.split('&').find_map(|field| field.strip_prefix("answer=")).unwrap_or("");
+        let page = match answer.parse::<i64>() {
+            Ok(42) => RIGHT,
+            Ok(n) if n > 42 => HIGH,
+            Ok(_) => LOW,
+            Err(_) if answer == "soon" => WAIT,
+            Err(_) => WRONG
+        };
+        ("200 OK", page.to_string())
+    })
+}
+
+fn submitter(url: &str, name: &str) -> Submitter {
+    let cache: PathBuf = env::temp_dir().join(format!("aoc-submit-test-{}", name));
+    let _ = fs::remove_dir_all(&cache);
+    let config = Config {
+        session: Some("cookie".to_string()),
+        base_url: url.to_string(),
+        interval: Duration::from_millis(0),
+        ..Config::default()
+    };
+    Submitter::new(config, &cache)
+}
+
+#[test]
+fn replies() {
+    assert_eq!(parse_reply(RIGHT), Ok(Reply::Verdict(Verdict::Correct)));
+    assert_eq!(parse_reply(HIGH), Ok(Reply::Verdict(Verdict::TooHigh)));
+    assert_eq!(parse_reply(LOW), Ok(Reply::Verdict(Verdict::TooLow)));
+    assert_eq!(parse_reply(WRONG), Ok(Reply::Verdict(Verdict::Wrong)));
+    assert_eq!(parse_reply(WAIT), Ok(Reply::Wait(Duration::from_secs(65))));
+    assert_eq!(parse_reply("You gave an answer too recently. You have 9s left to wait."), Ok(Reply::Wait(Duration::from_secs(9))));
+    assert_eq!(parse_reply(LEVEL), Ok(Reply::WrongLevel));
+    assert!(parse_reply("<html>Log in</html>").is_err());
+}
+
+#[test]
+fn narrowing_in() {
+    let (url, requests) = serve();
+    let submitter = submitter(&url, "narrowing");
+    let mut log = Log::default();
+
+    assert_eq!(submitter.submit(&mut log, 2019, 4, 1, "100").unwrap(), Reply::Verdict(Verdict::TooHigh));
+    assert_eq!(submitter.submit(&mut log, 2019, 4, 1, "7").unwrap(), Reply::Verdict(Verdict::TooLow));
+    assert_eq!(submitter.submit(&mut log, 2019, 4, 1, "soon").unwrap(), Reply::Wait(Duration::from_secs(65)));
+    assert_eq!(submitter.submit(&mut log, 2019, 4, 1, "42").unwrap(), Reply::Verdict(Verdict::Correct));
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 4);
+    assert_eq!((requests[0].method.as_str(), requests[0].path.as_str()), ("POST", "/2019/day/4/answer"));
+    assert_eq!(requests[0].cookie.as_deref(), Some("session=cookie"));
+    assert_eq!(requests[0].body, "level=1&answer=100");
+
+    // waiting isn't a verdict
+    let logged = log.submissions.iter().map(|s| (s.verdict, s.answer.as_str())).collect::<Vec<_>>();
+    assert_eq!(logged, vec![(Verdict::TooHigh, "100"), (Verdict::TooLow, "7"), (Verdict::Correct, "42")]);
+}
+
+#[test]
+fn known_wrong_answers_stay_home() {
+    let (url, requests) = serve();
+    let submitter = submitter(&url, "refusals");
+    let mut log = Log::default();
+
+    submitter.submit(&mut log, 2019, 4, 2, "100").unwrap();
+    submitter.submit(&mut log, 2019, 4, 2, "7").unwrap();
+    submitter.submit(&mut log, 2019, 4, 2, "many").unwrap();
+    for answer in &["100", "101", "7", "3", "many"] {
+        let error = submitter.submit(&mut log, 2019, 4, 2, answer).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput, "{}", answer);
+    };
+    assert_eq!(requests.lock().unwrap().len(), 3);
+
+    // the other part's bounds are its own
+    assert!(log.check(2019, 4, 1, "100").is_ok());
+    assert!(log.check(2019, 4, 2, "50").is_ok());
+
+    submitter.submit(&mut log, 2019, 4, 2, "42").unwrap();
+    assert!(log.check(2019, 4, 2, "50").is_err());
+}
+
+#[test]
+fn log_round_trip() {
+    let log = Log {
+        submissions: vec![
+            Submission { year: 2019, day: 9, part: 1, verdict: Verdict::Wrong, answer: "203 0".to_string() },
+            Submission { year: 2019, day: 4, part: 2, verdict: Verdict::TooLow, answer: "7".to_string() }
+        ]
+    };
+    assert_eq!(Log::parse(&log.to_string()).unwrap(), log);
+    assert!(Log::parse("2019 4 2 maybe 7").is_err());
+    assert!(Log::parse("2019 4 2 wrong").is_err());
+    assert!(Log::parse("2019 4 3 wrong 7").is_err());
+    assert!(Log::parse("2019 4 257 wrong 7").is_err());
+}
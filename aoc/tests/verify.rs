@@ -0,0 +1,39 @@
+use std::error::Error;
+
+use aoc::verify::{self, Outcome};
+use aoc::{Answer, Answers, Day, Solution};
+
+
+struct Nothing;
+
+impl Solution for Nothing {
+    type Input = ();
+
+    fn parse(_input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(())
+    }
+
+    fn part1(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+// long before any of this, so it has no directory
+const DAY: Day = Day { year: 1999, day: 1, solve: aoc::solve::<Nothing>, solve_part: aoc::solve_part::<Nothing> };
+
+#[test]
+fn days_without_inputs_are_still_reported() {
+    let mut answers = Answers::default();
+    assert!(verify::inputs(&DAY, &answers).is_empty());
+
+    let checks = verify::no_inputs(&DAY);
+    assert_eq!(checks.iter().map(|check| (check.part, check.outcome)).collect::<Vec<_>>(), vec![(1, Outcome::Missing), (2, Outcome::Missing)]);
+    assert!(verify::table(&checks).contains("no answers recorded"));
+
+    // and there's nothing in them to record
+    assert_eq!(verify::update(&mut answers, &checks), 0);
+}
@@ -0,0 +1,160 @@
+use std::error::Error;
+use std::fmt;
+
+// the block alphabets AoC draws its answers in. letters that have never shown
+// up in a puzzle are missing, since there's no reference drawing for them
+const SMALL: &[(char, [&str; 6])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+const LARGE: &[(char, [&str; 10])] = &[
+    ('A', ["..##..", ".#..#.", "#....#", "#....#", "#....#",
+           "######", "#....#", "#....#", "#....#", "#....#"]),
+    ('B', ["#####.", "#....#", "#....#", "#....#", "#####.",
+           "#....#", "#....#", "#....#", "#....#", "#####."]),
+    ('C', [".####.", "#....#", "#.....", "#.....", "#.....",
+           "#.....", "#.....", "#.....", "#....#", ".####."]),
+    ('E', ["######", "#.....", "#.....", "#.....", "#####.",
+           "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('F', ["######", "#.....", "#.....", "#.....", "#####.",
+           "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('G', [".####.", "#....#", "#.....", "#.....", "#.....",
+           "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+    ('H', ["#....#", "#....#", "#....#", "#....#", "######",
+           "#....#", "#....#", "#....#", "#....#", "#....#"]),
+    ('J', ["...###", "....#.", "....#.", "....#.", "....#.",
+           "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+    ('K', ["#....#", "#...#.", "#..#..", "#.#...", "##....",
+           "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+    ('L', ["#.....", "#.....", "#.....", "#.....", "#.....",
+           "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('N', ["#....#", "##...#", "##...#", "#.#..#", "#.#..#",
+           "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+    ('P', ["#####.", "#....#", "#....#", "#....#", "#####.",
+           "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('R', ["#####.", "#....#", "#....#", "#....#", "#####.",
+           "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+    ('X', ["#....#", "#....#", ".#..#.", ".#..#.", "..##..",
+           "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+    ('Z', ["######", ".....#", ".....#", "....#.", "...#..",
+           "..#...", ".#....", "#.....", "#.....", "######"]),
+];
+
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum OcrError {
+    Empty,
+    UnsupportedHeight(usize),
+    UnknownGlyph { index: usize, glyph: Vec<String> }
+}
+
+impl fmt::Display for OcrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OcrError::Empty =>
+                write!(f, "Nothing to read"),
+            OcrError::UnsupportedHeight(height) =>
+                write!(f, "No font is {} pixels tall", height),
+            OcrError::UnknownGlyph { index, glyph } =>
+                write!(f, "Unrecognized glyph at position {}:\n{}", index, glyph.join("\n"))
+        }
+    }
+}
+
+impl Error for OcrError {}
+
+
+// drops the blank columns on either side of a drawing, so glyphs that don't
+// fill their whole cell (like the small I) line up with what was segmented
+fn trim_columns(rows: &[&str]) -> Vec<String> {
+    let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    let lit = |c: usize| rows.iter().any(|row| row.as_bytes().get(c) == Some(&b'#'));
+    let (first, last) = match ((0..width).find(|&c| lit(c)), (0..width).rev().find(|&c| lit(c))) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return rows.iter().map(|_| String::new()).collect()
+    };
+
+    rows
+        .iter()
+        .map(|row| row[first..=last].to_string())
+        .collect()
+}
+
+fn lookup<'a, I>(glyph: &[String], font: I) -> Option<char>
+    where I: Iterator<Item = (char, &'a [&'a str])>
+{
+    for (letter, drawing) in font {
+        if trim_columns(drawing) == glyph {
+            return Some(letter);
+        };
+    };
+
+    None
+}
+
+pub fn recognize(grid: &[Vec<bool>]) -> Result<String, OcrError> {
+    let width = grid.iter().map(|row| row.len()).max().unwrap_or(0);
+    let lit = |r: usize, c: usize| *grid[r].get(c).unwrap_or(&false);
+
+    let rows = (0..grid.len())
+        .filter(|&r| (0..width).any(|c| lit(r, c)))
+        .collect::<Vec<_>>();
+    let (top, bottom) = match (rows.first(), rows.last()) {
+        (Some(&top), Some(&bottom)) => (top, bottom),
+        _ => return Err(OcrError::Empty)
+    };
+    let height = bottom - top + 1;
+    if height != 6 && height != 10 {
+        return Err(OcrError::UnsupportedHeight(height));
+    };
+
+    // letters are separated by at least one blank column
+    let mut glyphs: Vec<Vec<String>> = Vec::new();
+    let mut in_glyph = false;
+    for c in 0..width {
+        if (top..=bottom).any(|r| lit(r, c)) {
+            if !in_glyph {
+                glyphs.push(vec![String::new(); height]);
+                in_glyph = true;
+            };
+            let glyph = glyphs.last_mut().expect("No glyph started!");
+            for (row, r) in glyph.iter_mut().zip(top..=bottom) {
+                row.push(if lit(r, c) { '#' } else { '.' });
+            };
+        } else {
+            in_glyph = false;
+        };
+    };
+
+    let mut text = String::new();
+    for (index, glyph) in glyphs.into_iter().enumerate() {
+        let letter = if height == 6 {
+            lookup(&glyph, SMALL.iter().map(|(letter, drawing)| (*letter, &drawing[..])))
+        } else {
+            lookup(&glyph, LARGE.iter().map(|(letter, drawing)| (*letter, &drawing[..])))
+        };
+        match letter {
+            Some(letter) => text.push(letter),
+            None => return Err(OcrError::UnknownGlyph { index, glyph })
+        };
+    };
+
+    Ok(text)
+}
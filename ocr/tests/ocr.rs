@@ -0,0 +1,69 @@
+use ocr::{recognize, OcrError};
+
+
+// rows of `#` and `.`, as a grid
+fn grid(rows: &[&str]) -> Vec<Vec<bool>> {
+    rows.iter().map(|row| row.chars().map(|c| c == '#').collect()).collect()
+}
+
+#[test]
+fn small_letters() {
+    // "HI", with some blank space around it like the puzzles leave
+    let drawing = grid(&[
+        "...........",
+        ".#..#..###.",
+        ".#..#...#..",
+        ".####...#..",
+        ".#..#...#..",
+        ".#..#...#..",
+        ".#..#..###.",
+        "..........."
+    ]);
+    assert_eq!(recognize(&drawing), Ok("HI".to_string()));
+}
+
+#[test]
+fn large_letters() {
+    let drawing = grid(&[
+        "#....#..######",
+        "#....#.......#",
+        "#....#.......#",
+        "#....#......#.",
+        "######.....#..",
+        "#....#....#...",
+        "#....#...#....",
+        "#....#..#.....",
+        "#....#..#.....",
+        "#....#..######"
+    ]);
+    assert_eq!(recognize(&drawing), Ok("HZ".to_string()));
+}
+
+#[test]
+fn nothing_drawn() {
+    assert_eq!(recognize(&grid(&["....", "...."])), Err(OcrError::Empty));
+    assert_eq!(recognize(&[]), Err(OcrError::Empty));
+}
+
+#[test]
+fn unknown_letters() {
+    // an H, then something that isn't a letter
+    let drawing = grid(&[
+        "#..#.####",
+        "#..#.####",
+        "####.####",
+        "#..#.####",
+        "#..#.####",
+        "#..#.####"
+    ]);
+    assert_eq!(recognize(&drawing), Err(OcrError::UnknownGlyph {
+        index: 1,
+        glyph: vec!["####".to_string(); 6]
+    }));
+}
+
+#[test]
+fn unsupported_heights() {
+    let drawing = grid(&["#", "#", "#", "#", "#", "#", "#"]);
+    assert_eq!(recognize(&drawing), Err(OcrError::UnsupportedHeight(7)));
+}
@@ -0,0 +1,106 @@
+use std::io::{self, Stdout, Write};
+use std::process;
+
+use std::sync::Once;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use termion::{clear, cursor};
+
+
+// a terminal that redraws only what changed between frames. frames are plain
+// text, one line per row, drawn below a status line. the last frame stays up
+// afterwards, with the cursor put back underneath it
+
+// rows in use, for the Ctrl-C handler to know where to leave the cursor
+static HEIGHT: AtomicUsize = AtomicUsize::new(0);
+static HANDLER: Once = Once::new();
+
+fn restore_on_interrupt() {
+    HANDLER.call_once(|| {
+        let handler = ctrlc::set_handler(|| {
+            let below = HEIGHT.load(Ordering::SeqCst) as u16 + 2;
+            print!("{}{}", cursor::Goto(1, below), cursor::Show);
+            let _ = io::stdout().flush();
+            process::exit(130);
+        });
+        if let Err(e) = handler {
+            eprintln!("Couldn't catch Ctrl-C, so the cursor may stay hidden: {}", e);
+        };
+    });
+}
+
+pub struct Screen<W: Write = Stdout> {
+    out: W,
+    status: String,
+    previous: Vec<Vec<char>>
+}
+
+impl Screen {
+    pub fn new() -> Result<Self, io::Error> {
+        restore_on_interrupt();
+        Screen::with_output(io::stdout())
+    }
+}
+
+impl<W: Write> Screen<W> {
+    pub fn with_output(mut out: W) -> Result<Self, io::Error> {
+        write!(out, "{}{}{}", clear::All, cursor::Hide, cursor::Goto(1, 1))?;
+        out.flush()?;
+        Ok(Screen { out, status: String::new(), previous: Vec::new() })
+    }
+
+    pub fn draw(&mut self, frame: &str, status: &str) -> Result<(), io::Error> {
+        if status != self.status {
+            write!(self.out, "{}{}{}", cursor::Goto(1, 1), clear::CurrentLine, status)?;
+            self.status = status.to_string();
+        };
+
+        let rows = frame.lines().map(|line| line.chars().collect()).collect::<Vec<Vec<char>>>();
+        let at = |grid: &Vec<Vec<char>>, x: usize, y: usize| *grid
+            .get(y)
+            .and_then(|row| row.get(x))
+            .unwrap_or(&' ');
+
+        // where the cursor is after the last write, so a run of changed cells
+        // only needs moving to once
+        let mut cursor_at = None;
+        for y in 0..rows.len().max(self.previous.len()) {
+            let width = rows.get(y).map_or(0, Vec::len).max(self.previous.get(y).map_or(0, Vec::len));
+            for x in 0..width {
+                let cell = at(&rows, x, y);
+                if cell == at(&self.previous, x, y) {
+                    continue;
+                };
+                if cursor_at != Some((x, y)) {
+                    write!(self.out, "{}", cursor::Goto(x as u16 + 1, y as u16 + 2))?;
+                };
+                write!(self.out, "{}", cell)?;
+                cursor_at = Some((x + 1, y));
+            };
+        };
+
+        self.previous = rows;
+        HEIGHT.store(self.previous.len(), Ordering::SeqCst);
+        self.out.flush()
+    }
+}
+
+impl<W: Write> Drop for Screen<W> {
+    fn drop(&mut self) {
+        let below = self.previous.len() as u16 + 2;
+        let _ = write!(self.out, "{}{}", cursor::Goto(1, below), cursor::Show);
+        let _ = self.out.flush();
+    }
+}
+
+
+// the corners of the smallest box around some points, in one pass
+pub fn bounds<'a, I>(points: I) -> Option<((i64, i64), (i64, i64))>
+    where I: IntoIterator<Item = &'a (i64, i64)>
+{
+    points.into_iter().fold(None, |bounds, &(x, y)| match bounds {
+        None => Some(((x, y), (x, y))),
+        Some(((min_x, min_y), (max_x, max_y))) =>
+            Some(((min_x.min(x), min_y.min(y)), (max_x.max(x), max_y.max(y))))
+    })
+}
@@ -0,0 +1,31 @@
+use screen::{bounds, Screen};
+use termion::{clear, cursor};
+
+
+#[test]
+fn only_changes_are_drawn() {
+    let mut out = Vec::new();
+    {
+        let mut screen = Screen::with_output(&mut out).unwrap();
+        screen.draw("ab\ncd", "Step 1").unwrap();
+        screen.draw("ab\nxy\ne", "Step 1").unwrap();
+        screen.draw("a", "Step 2").unwrap();
+    }
+
+    let expected = [
+        format!("{}{}{}", clear::All, cursor::Hide, cursor::Goto(1, 1)),
+        format!("{}{}Step 1", cursor::Goto(1, 1), clear::CurrentLine),
+        format!("{}ab{}cd", cursor::Goto(1, 2), cursor::Goto(1, 3)),
+        format!("{}xy{}e", cursor::Goto(1, 3), cursor::Goto(1, 4)),
+        format!("{}{}Step 2", cursor::Goto(1, 1), clear::CurrentLine),
+        format!("{} {}  {} ", cursor::Goto(2, 2), cursor::Goto(1, 3), cursor::Goto(1, 4)),
+        format!("{}{}", cursor::Goto(1, 3), cursor::Show)
+    ].concat();
+    assert_eq!(String::from_utf8(out).unwrap(), expected);
+}
+
+#[test]
+fn bounds_of_points() {
+    assert_eq!(bounds(&[(3, -1), (-2, 4), (0, 0)]), Some(((-2, -1), (3, 4))));
+    assert_eq!(bounds(&[]), None);
+}